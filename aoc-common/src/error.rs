@@ -0,0 +1,105 @@
+use crate::input::InputError;
+use std::error;
+use std::fmt;
+
+#[cfg(test)]
+mod tests {
+    use crate::error::*;
+
+    #[test]
+    fn test_parse_error_display() {
+        let error = ParseError {
+            name: String::from("data/input"),
+            line: 3,
+            column: 8,
+            token: String::from("purple"),
+            message: String::from("unknown color"),
+        };
+        assert_eq!(
+            error.to_string(),
+            "data/input:3:8: unknown color, found \"purple\""
+        );
+    }
+
+    #[test]
+    fn test_parse_error_display_without_token() {
+        let error = ParseError {
+            name: String::from("data/input"),
+            line: 3,
+            column: 12,
+            token: String::new(),
+            message: String::from("expected bid"),
+        };
+        assert_eq!(error.to_string(), "data/input:3:12: expected bid");
+    }
+}
+
+/// Error found while parsing the puzzle input
+///
+/// Points to the place of the input where the parser found an unexpected
+/// token. Line and column numbers start from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Name of the input that was being parsed
+    pub name: String,
+    pub line: usize,
+    pub column: usize,
+    /// Offending token (empty if the parser found nothing where it expected something)
+    pub token: String,
+    /// Description of what the parser was expecting
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: {}",
+            self.name, self.line, self.column, self.message
+        )?;
+        if !self.token.is_empty() {
+            // Use debug formatting so invisible characters (like '\r') show up
+            write!(f, ", found {:?}", self.token)?;
+        }
+        Ok(())
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Error raised while loading or parsing the puzzle input
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Parse(ParseError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Input(error) => error.fmt(f),
+            Error::Parse(error) => error.fmt(f),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Input(error) => Some(error),
+            Error::Parse(error) => Some(error),
+        }
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(error: InputError) -> Self {
+        Error::Input(error)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Error::Parse(error)
+    }
+}
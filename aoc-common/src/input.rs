@@ -0,0 +1,363 @@
+use crate::error::ParseError;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+
+#[cfg(test)]
+mod tests {
+    use crate::input::*;
+
+    #[test]
+    fn test_from_string() {
+        let input = RawInput::from_string("example", "abc\ndef\n");
+        assert_eq!(input.name(), "example");
+        assert_eq!(input.content(), "abc\ndef\n");
+        let input = RawInput::from("abc");
+        assert_eq!(input.name(), "<string>");
+    }
+
+    #[test]
+    fn test_from_path() {
+        let input = RawInput::from_path("Cargo.toml").unwrap();
+        assert_eq!(input.name(), "Cargo.toml");
+        assert!(input.content().starts_with("[package]"));
+    }
+
+    #[test]
+    fn test_from_missing_path() {
+        let error = RawInput::from_path("data/missing_file").unwrap_err();
+        assert_eq!(error.name(), "data/missing_file");
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+        assert!(error
+            .to_string()
+            .starts_with("couldn't read data/missing_file: "));
+    }
+
+    #[test]
+    fn test_from_reader() {
+        let reader = "abc\ndef".as_bytes();
+        let input = RawInput::from_reader("<stdin>", reader).unwrap();
+        assert_eq!(input.name(), "<stdin>");
+        assert_eq!(input.content(), "abc\ndef");
+    }
+
+    #[test]
+    fn test_lines() {
+        let input = RawInput::from("abc\r\n\ndef\n");
+        let lines: Vec<Line> = input.lines().collect();
+        let expected = vec![
+            Line {
+                name: "<string>",
+                number: 1,
+                text: "abc",
+            },
+            Line {
+                name: "<string>",
+                number: 2,
+                text: "",
+            },
+            Line {
+                name: "<string>",
+                number: 3,
+                text: "def",
+            },
+        ];
+        assert_eq!(lines, expected);
+    }
+
+    #[test]
+    fn test_line_error() {
+        let input = RawInput::from_string(
+            "data/input",
+            "abc
+Game 1: 3 purple",
+        );
+        let line = input.lines().nth(1).unwrap();
+        let error = line.error(&line.text[10..], "unknown color");
+        assert_eq!(error.name, "data/input");
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 11);
+        assert_eq!(error.token, "purple");
+        assert_eq!(error.message, "unknown color");
+        // Tokens that are not part of the line are placed at its start
+        let error = line.error("blue", "unknown color");
+        assert_eq!(error.column, 1);
+        assert_eq!(error.token, "blue");
+    }
+
+    #[test]
+    fn test_line_error_columns_count_chars() {
+        let input = RawInput::from("αβ x");
+        let line = input.lines().next().unwrap();
+        let error = line.error(&line.text[5..], "invalid character");
+        assert_eq!(error.column, 4);
+    }
+
+    #[test]
+    fn test_line_error_at_end() {
+        let input = RawInput::from("32T3K");
+        let line = input.lines().next().unwrap();
+        let error = line.error_at_end("expected bid");
+        assert_eq!(error.line, 1);
+        assert_eq!(error.column, 6);
+        assert_eq!(error.token, "");
+    }
+
+    #[test]
+    fn test_input_error_at_end() {
+        let input = RawInput::from_string(
+            "data/input",
+            "abc
+def
+",
+        );
+        let error = input.error_at_end("expected map");
+        assert_eq!(error.name, "data/input");
+        assert_eq!(error.line, 3);
+        assert_eq!(error.column, 1);
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = RawInput::from("a\nb\n\nc\n  \n\n\nd\ne\n\n");
+        let blocks: Vec<Block> = input.blocks().collect();
+        assert_eq!(blocks.len(), 3);
+        let texts: Vec<Vec<&str>> = blocks
+            .iter()
+            .map(|block| block.iter().map(|line| line.text).collect())
+            .collect();
+        assert_eq!(texts, vec![vec!["a", "b"], vec!["c"], vec!["d", "e"]]);
+        // Blocks keep the line numbers of the whole input
+        assert_eq!(blocks[1][0].number, 4);
+        assert_eq!(blocks[2][0].number, 8);
+    }
+
+    #[test]
+    fn test_blocks_empty_input() {
+        let input = RawInput::from("\n\n");
+        assert_eq!(input.blocks().count(), 0);
+    }
+}
+
+/// Puzzle input loaded into memory
+///
+/// The input keeps the name of where it was read from (a path, the standard
+/// input, etc), so errors found while parsing it can point to it.
+#[derive(Debug, Clone)]
+pub struct RawInput {
+    name: String,
+    content: String,
+}
+
+impl RawInput {
+    /// Read the input from a file
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, InputError> {
+        let name = path.as_ref().display().to_string();
+        match fs::read_to_string(path) {
+            Ok(content) => Ok(Self { name, content }),
+            Err(error) => Err(InputError { name, error }),
+        }
+    }
+
+    /// Read the input from the standard input
+    pub fn from_stdin() -> Result<Self, InputError> {
+        Self::from_reader("<stdin>", io::stdin().lock())
+    }
+
+    /// Read the whole input out of a reader
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - name used to refer to the input in error messages
+    /// * `reader` - reader from which the input will be read
+    pub fn from_reader<R: Read>(name: &str, mut reader: R) -> Result<Self, InputError> {
+        let mut content = String::new();
+        match reader.read_to_string(&mut content) {
+            Ok(_) => Ok(Self::from_string(name, content)),
+            Err(error) => Err(InputError {
+                name: String::from(name),
+                error,
+            }),
+        }
+    }
+
+    /// Create an input out of a string that lives in memory
+    pub fn from_string<S: Into<String>>(name: &str, content: S) -> Self {
+        Self {
+            name: String::from(name),
+            content: content.into(),
+        }
+    }
+
+    /// Name of the source the input was read from
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    /// Iterate over the lines of the input, along with their line numbers
+    pub fn lines(&self) -> Lines<'_> {
+        Lines {
+            name: &self.name,
+            lines: self.content.lines().enumerate(),
+        }
+    }
+
+    /// Iterate over the blocks of consecutive lines separated by blank lines
+    pub fn blocks(&self) -> Blocks<'_> {
+        Blocks {
+            lines: self.lines(),
+        }
+    }
+
+    /// Create an error pointing right after the last line of the input
+    ///
+    /// Useful to report inputs that end before the parser expected them to.
+    pub fn error_at_end(&self, message: &str) -> ParseError {
+        ParseError {
+            name: self.name.clone(),
+            line: self.content.lines().count() + 1,
+            column: 1,
+            token: String::new(),
+            message: String::from(message),
+        }
+    }
+}
+
+impl From<&str> for RawInput {
+    fn from(content: &str) -> Self {
+        Self::from_string("<string>", content)
+    }
+}
+
+/// Error raised when the input cannot be read
+#[derive(Debug)]
+pub struct InputError {
+    name: String,
+    error: io::Error,
+}
+
+impl InputError {
+    /// Name of the source that couldn't be read
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn kind(&self) -> io::ErrorKind {
+        self.error.kind()
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "couldn't read {}: {}", self.name, self.error)
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Single line of the input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    name: &'a str,
+    /// Number of the line in the input, starting from 1
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Create an error pointing to a token found in the line
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - offending token, ideally a slice of the text of the line
+    ///   so its column can be located. Tokens that don't belong to the line
+    ///   are placed in the first column.
+    /// * `message` - description of what the parser was expecting
+    pub fn error(&self, token: &str, message: &str) -> ParseError {
+        let start = self.text.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize).wrapping_sub(start);
+        let column = match self.text.get(..offset) {
+            Some(before) if offset + token.len() <= self.text.len() => before.chars().count() + 1,
+            _ => 1,
+        };
+        ParseError {
+            name: String::from(self.name),
+            line: self.number,
+            column,
+            token: String::from(token),
+            message: String::from(message),
+        }
+    }
+
+    /// Create an error pointing right after the last character of the line
+    ///
+    /// Useful to report lines that end before the parser expected them to.
+    pub fn error_at_end(&self, message: &str) -> ParseError {
+        ParseError {
+            name: String::from(self.name),
+            line: self.number,
+            column: self.text.chars().count() + 1,
+            token: String::new(),
+            message: String::from(message),
+        }
+    }
+}
+
+/// Iterator over the lines of a [`RawInput`]
+pub struct Lines<'a> {
+    name: &'a str,
+    lines: std::iter::Enumerate<std::str::Lines<'a>>,
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = Line<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, text) = self.lines.next()?;
+        Some(Line {
+            name: self.name,
+            number: i + 1,
+            text,
+        })
+    }
+}
+
+/// Group of consecutive non-blank lines
+pub type Block<'a> = Vec<Line<'a>>;
+
+/// Iterator over the blocks of lines of a [`RawInput`]
+///
+/// Blocks are separated by one or more blank lines, so no empty block is ever
+/// returned.
+pub struct Blocks<'a> {
+    lines: Lines<'a>,
+}
+
+impl<'a> Iterator for Blocks<'a> {
+    type Item = Block<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut block = vec![];
+        for line in self.lines.by_ref() {
+            if !line.text.trim().is_empty() {
+                block.push(line);
+            } else if !block.is_empty() {
+                break;
+            }
+        }
+        if block.is_empty() {
+            return None;
+        }
+        Some(block)
+    }
+}
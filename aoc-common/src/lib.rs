@@ -0,0 +1,8 @@
+//! Utilities shared by the solutions to every day of the calendar
+mod error;
+mod input;
+mod solution;
+
+pub use error::{Error, ParseError};
+pub use input::{Block, Blocks, InputError, Line, Lines, RawInput};
+pub use solution::Solution;
@@ -0,0 +1,80 @@
+use crate::error::{Error, ParseError};
+use crate::input::RawInput;
+use std::fmt::Display;
+use std::path::Path;
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+        type Answer = u32;
+
+        fn parse(input: &RawInput) -> Result<Self::Input, ParseError> {
+            input
+                .lines()
+                .map(|line| {
+                    line.text
+                        .parse()
+                        .map_err(|_| line.error(line.text, "invalid number"))
+                })
+                .collect()
+        }
+
+        fn part1(input: &Self::Input) -> Option<Self::Answer> {
+            Some(input.iter().sum())
+        }
+    }
+
+    #[test]
+    fn test_solution() {
+        let input = RawInput::from("1\n2\n3\n");
+        let input = Sum::parse(&input).unwrap();
+        assert_eq!(Sum::part1(&input), Some(6));
+        assert_eq!(Sum::part2(&input), None);
+    }
+
+    #[test]
+    fn test_parse_file_errors() {
+        let error = Sum::parse_file("data/missing_file").unwrap_err();
+        assert!(matches!(error, Error::Input(_)));
+        let error = Sum::parse_file("Cargo.toml").unwrap_err();
+        assert!(matches!(error, Error::Parse(_)));
+    }
+}
+
+/// Solution to the puzzle of a single day
+///
+/// The input is parsed only once into the types of the puzzle, and then it's
+/// shared by the solvers of both parts, which don't need to do any IO.
+pub trait Solution {
+    /// Puzzle input parsed into the types of the puzzle
+    type Input;
+    /// Answer to the parts of the puzzle
+    type Answer: Display;
+
+    fn parse(input: &RawInput) -> Result<Self::Input, ParseError>;
+
+    /// Solve the first part of the puzzle
+    ///
+    /// Return `None` if the input has no answer to the first part, e.g. an
+    /// example that was written only for the second one.
+    fn part1(input: &Self::Input) -> Option<Self::Answer>;
+
+    /// Solve the second part of the puzzle
+    ///
+    /// Return `None` if the second part hasn't been solved yet, or if the
+    /// input has no answer to it.
+    fn part2(_input: &Self::Input) -> Option<Self::Answer> {
+        None
+    }
+
+    /// Read and parse the input from a file
+    fn parse_file<P: AsRef<Path>>(path: P) -> Result<Self::Input, Error> {
+        let input = RawInput::from_path(path)?;
+        Ok(Self::parse(&input)?)
+    }
+}
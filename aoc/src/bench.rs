@@ -0,0 +1,314 @@
+//! Benchmark the parsing and the solutions to every day of the calendar
+use crate::days::Day;
+use aoc_common::{Error, Line, ParseError, RawInput};
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::fs;
+use std::hint::black_box;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::*;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|x| Duration::from_nanos(*x)).collect()
+    }
+
+    #[test]
+    fn test_stats_from_samples() {
+        let samples = nanos(&[7, 3, 1, 9, 5]);
+        let stats = Stats::from_samples(samples);
+        assert_eq!(stats.min, Duration::from_nanos(1));
+        assert_eq!(stats.median, Duration::from_nanos(5));
+        assert_eq!(stats.p95, Duration::from_nanos(9));
+        let samples = nanos(&(1..=100).collect::<Vec<u64>>());
+        let stats = Stats::from_samples(samples);
+        assert_eq!(stats.min, Duration::from_nanos(1));
+        assert_eq!(stats.median, Duration::from_nanos(51));
+        assert_eq!(stats.p95, Duration::from_nanos(95));
+    }
+
+    #[test]
+    fn test_baseline_roundtrip() {
+        let measurements = vec![
+            Measurement {
+                day: 6,
+                name: String::from("parse"),
+                stats: Stats::from_samples(nanos(&[10, 20, 30])),
+            },
+            Measurement {
+                day: 6,
+                name: String::from("part1-brute-force"),
+                stats: Stats::from_samples(nanos(&[1000, 2000])),
+            },
+        ];
+        let input = RawInput::from(format_baseline(&measurements).as_str());
+        let baseline = parse_baseline(&input).unwrap();
+        assert_eq!(baseline.len(), 2);
+        let stats = &baseline[&(6, String::from("parse"))];
+        assert_eq!(stats.median, Duration::from_nanos(20));
+        let stats = &baseline[&(6, String::from("part1-brute-force"))];
+        assert_eq!(stats.min, Duration::from_nanos(1000));
+    }
+
+    #[test]
+    fn test_parse_baseline_errors() {
+        let input = RawInput::from("# comment\n06 parse 1 2\n06 part1 1 x 3");
+        let error = parse_baseline(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        let input = RawInput::from("06 part1 1 x 3");
+        let error = parse_baseline(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 12));
+        assert_eq!(error.token, "x");
+    }
+
+    #[test]
+    fn test_change() {
+        let baseline = Stats::from_samples(nanos(&[100]));
+        let stats = Stats::from_samples(nanos(&[150]));
+        assert_eq!(change(&stats, &baseline), 50.0);
+        assert!((change(&baseline, &stats) + 100.0 / 3.0).abs() < 1e-9);
+    }
+}
+
+/// Statistics of the time taken by the iterations of a benchmark
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Compute the statistics out of a non-empty list of samples
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty());
+        samples.sort();
+        let percentile = |p: usize| samples[(samples.len() * p).div_ceil(100).max(1) - 1];
+        Stats {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            p95: percentile(95),
+        }
+    }
+}
+
+/// Statistics of a single benchmark of a day
+#[derive(Debug)]
+pub struct Measurement {
+    pub day: u8,
+    /// Name of what was measured: `parse`, `part1`, `part2` or a variant
+    pub name: String,
+    pub stats: Stats,
+}
+
+/// Alternative solver to one part of a day, benchmarked along with it
+struct Variant {
+    day: u8,
+    name: &'static str,
+    solver: fn(&dyn Any) -> String,
+}
+
+fn races(input: &dyn Any) -> &day_06::Races {
+    input
+        .downcast_ref()
+        .expect("input should be parsed by day 6")
+}
+
+static VARIANTS: [Variant; 2] = [
+    Variant {
+        day: 6,
+        name: "part1-brute-force",
+        solver: |input| day_06::solve_part1_brute_force(races(input)).to_string(),
+    },
+    Variant {
+        day: 6,
+        name: "part2-brute-force",
+        solver: |input| day_06::solve_part2_brute_force(races(input)).to_string(),
+    },
+];
+
+/// Run a function repeatedly and return the statistics of its timings
+///
+/// The function is run once before starting to measure, so the input and
+/// the caches are warmed up.
+fn measure<F: FnMut()>(iterations: usize, mut function: F) -> Stats {
+    function();
+    let mut samples = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let now = Instant::now();
+        function();
+        samples.push(now.elapsed());
+    }
+    Stats::from_samples(samples)
+}
+
+/// Benchmark the parsing and every solved part of a day
+///
+/// # Arguments
+///
+/// * `day` - day to benchmark, its puzzle input is read only once
+/// * `iterations` - number of times each benchmark is run
+pub fn bench_day(day: &Day, iterations: usize) -> Result<Vec<Measurement>, Error> {
+    let input = RawInput::from_path(day.input())?;
+    let parsed = day.parse(&input)?;
+    let parsed = parsed.as_ref();
+    let mut measurements = vec![];
+    let stats = measure(iterations, || {
+        let _ = black_box(day.parse(black_box(&input)));
+    });
+    measurements.push(Measurement {
+        day: day.number,
+        name: String::from("parse"),
+        stats,
+    });
+    for part in [1, 2] {
+        if day.solve(part, parsed).is_none() {
+            continue;
+        }
+        let stats = measure(iterations, || {
+            black_box(day.solve(part, black_box(parsed)));
+        });
+        measurements.push(Measurement {
+            day: day.number,
+            name: format!("part{}", part),
+            stats,
+        });
+    }
+    for variant in VARIANTS.iter().filter(|v| v.day == day.number) {
+        let stats = measure(iterations, || {
+            black_box((variant.solver)(black_box(parsed)));
+        });
+        measurements.push(Measurement {
+            day: day.number,
+            name: String::from(variant.name),
+            stats,
+        });
+    }
+    Ok(measurements)
+}
+
+/// Statistics of a previous run, indexed by day and name of the benchmark
+pub type Baseline = HashMap<(u8, String), Stats>;
+
+/// Write the measurements in the plain text format of the baselines
+///
+/// Each line holds the day, the name of the benchmark and its min, median
+/// and p95 times in nanoseconds.
+fn format_baseline(measurements: &[Measurement]) -> String {
+    let mut content = String::from("# day name min_ns median_ns p95_ns\n");
+    for m in measurements {
+        writeln!(
+            content,
+            "{:02} {} {} {} {}",
+            m.day,
+            m.name,
+            m.stats.min.as_nanos(),
+            m.stats.median.as_nanos(),
+            m.stats.p95.as_nanos()
+        )
+        .unwrap();
+    }
+    content
+}
+
+pub fn save_baseline<P: AsRef<Path>>(path: P, measurements: &[Measurement]) -> io::Result<()> {
+    fs::write(path, format_baseline(measurements))
+}
+
+pub fn load_baseline<P: AsRef<Path>>(path: P) -> Result<Baseline, Error> {
+    let input = RawInput::from_path(path)?;
+    Ok(parse_baseline(&input)?)
+}
+
+fn parse_baseline(input: &RawInput) -> Result<Baseline, ParseError> {
+    let mut baseline = HashMap::new();
+    for line in input.lines() {
+        let text = line.text.trim();
+        if text.is_empty() || text.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = text.split_whitespace().collect();
+        if fields.len() != 5 {
+            return Err(line.error(text, "expected day, name, min, median and p95"));
+        }
+        let day = match fields[0].parse() {
+            Ok(day) => day,
+            Err(_) => return Err(line.error(fields[0], "invalid day")),
+        };
+        let stats = Stats {
+            min: parse_nanos(&line, fields[2])?,
+            median: parse_nanos(&line, fields[3])?,
+            p95: parse_nanos(&line, fields[4])?,
+        };
+        baseline.insert((day, String::from(fields[1])), stats);
+    }
+    Ok(baseline)
+}
+
+fn parse_nanos(line: &Line, token: &str) -> Result<Duration, ParseError> {
+    match token.parse() {
+        Ok(nanos) => Ok(Duration::from_nanos(nanos)),
+        Err(_) => Err(line.error(token, "invalid time in nanoseconds")),
+    }
+}
+
+/// Relative change of the median time with respect to the baseline, in %
+fn change(stats: &Stats, baseline: &Stats) -> f64 {
+    let current = stats.median.as_secs_f64();
+    let previous = baseline.median.as_secs_f64();
+    (current - previous) / previous * 100.0
+}
+
+/// Print a table with the measurements and return the number of regressions
+///
+/// # Arguments
+///
+/// * `measurements` - statistics of the benchmarks that were run
+/// * `baseline` - statistics to compare with, if any
+/// * `threshold` - increase of the median time (in %) over the baseline
+///   above which a benchmark is reported as a regression
+pub fn report(measurements: &[Measurement], baseline: Option<&Baseline>, threshold: f64) -> usize {
+    let mut n_regressions = 0;
+    print!(
+        "{:<5}{:<20}{:>12}{:>12}{:>12}",
+        "Day", "Benchmark", "min", "median", "p95"
+    );
+    if baseline.is_some() {
+        print!("{:>12}{:>10}", "baseline", "change");
+    }
+    println!();
+    for m in measurements {
+        print!(
+            "{:<5}{:<20}{:>12}{:>12}{:>12}",
+            format!("{:02}", m.day),
+            m.name,
+            format!("{:.2?}", m.stats.min),
+            format!("{:.2?}", m.stats.median),
+            format!("{:.2?}", m.stats.p95)
+        );
+        if let Some(baseline) = baseline {
+            match baseline.get(&(m.day, m.name.clone())) {
+                Some(previous) => {
+                    let change = change(&m.stats, previous);
+                    print!(
+                        "{:>12}{:>10}",
+                        format!("{:.2?}", previous.median),
+                        format!("{:+.1}%", change)
+                    );
+                    if change > threshold {
+                        n_regressions += 1;
+                        print!("  regressed");
+                    }
+                }
+                None => print!("{:>12}{:>10}", "-", "-"),
+            }
+        }
+        println!();
+    }
+    n_regressions
+}
@@ -0,0 +1,188 @@
+//! Check the answers of every day against the expected ones
+use crate::days::Day;
+use aoc_common::{Error, ParseError, RawInput};
+use std::io;
+
+#[cfg(test)]
+mod tests {
+    use crate::check::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let input = RawInput::from("part1: 54953\n\npart2: 53868\n");
+        let answers = parse_answers(&input).unwrap();
+        assert_eq!(answers.get(1), Some("54953"));
+        assert_eq!(answers.get(2), Some("53868"));
+        assert_eq!(answers.get(3), None);
+        let input = RawInput::from("part1: 282277027\n");
+        let answers = parse_answers(&input).unwrap();
+        assert_eq!(answers.get(2), None);
+    }
+
+    #[test]
+    fn test_parse_answers_errors() {
+        let input = RawInput::from("part1: 1\npart3: 2");
+        let error = parse_answers(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.token, "part3");
+        let input = RawInput::from("part1 1");
+        let error = parse_answers(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 8));
+        let input = RawInput::from("part1: 1\npart1: 2");
+        let error = parse_answers(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn test_status() {
+        assert_eq!(Status::new(Some("1"), Some("1")), Status::Pass);
+        assert_eq!(Status::new(Some("1"), Some("2")), Status::Fail);
+        assert_eq!(Status::new(None, Some("2")), Status::Missing);
+        assert_eq!(Status::new(Some("1"), None), Status::Unsolved);
+        assert_eq!(Status::new(None, None), Status::Unsolved);
+    }
+}
+
+/// Expected answers to the puzzle of a day
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+impl Answers {
+    /// Return the expected answer to a part, if it's known
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Load the expected answers of a day
+///
+/// Days without an answers file have no expected answers.
+pub fn load_answers(day: &Day) -> Result<Answers, Error> {
+    match RawInput::from_path(day.answers()) {
+        Ok(input) => Ok(parse_answers(&input)?),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+        Err(error) => Err(error.into()),
+    }
+}
+
+/// Parse the answers file, made of `part1: <answer>` and `part2: <answer>` lines
+fn parse_answers(input: &RawInput) -> Result<Answers, ParseError> {
+    let mut answers = Answers::default();
+    for line in input.lines() {
+        if line.text.trim().is_empty() {
+            continue;
+        }
+        let (part, answer) = match line.text.split_once(':') {
+            Some(parts) => parts,
+            None => return Err(line.error_at_end("expected ':' after the part")),
+        };
+        let slot = match part.trim() {
+            "part1" => &mut answers.part1,
+            "part2" => &mut answers.part2,
+            _ => return Err(line.error(part, "invalid part, expected 'part1' or 'part2'")),
+        };
+        if slot.is_some() {
+            return Err(line.error(part, "duplicated answer"));
+        }
+        *slot = Some(String::from(answer.trim()));
+    }
+    Ok(answers)
+}
+
+/// Result of checking the answer to one part of a puzzle
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail,
+    /// The part was solved, but there's no expected answer to compare with
+    Missing,
+    Unsolved,
+}
+
+impl Status {
+    fn new(expected: Option<&str>, actual: Option<&str>) -> Self {
+        match (expected, actual) {
+            (_, None) => Status::Unsolved,
+            (None, Some(_)) => Status::Missing,
+            (Some(expected), Some(actual)) if expected == actual => Status::Pass,
+            (Some(_), Some(_)) => Status::Fail,
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Missing => "missing",
+            Status::Unsolved => "not solved",
+        }
+    }
+}
+
+/// Answers to one part of a puzzle, along with the result of checking them
+struct PartCheck {
+    part: u8,
+    expected: Option<String>,
+    actual: Option<String>,
+    status: Status,
+}
+
+/// Solve both parts of a day with its real input and check their answers
+fn check_day(day: &Day) -> Result<Vec<PartCheck>, Error> {
+    let answers = load_answers(day)?;
+    let input = RawInput::from_path(day.input())?;
+    let parsed = day.parse(&input)?;
+    let mut checks = vec![];
+    for part in [1, 2] {
+        let expected = answers.get(part);
+        let actual = day.solve(part, parsed.as_ref());
+        checks.push(PartCheck {
+            part,
+            expected: expected.map(String::from),
+            status: Status::new(expected, actual.as_deref()),
+            actual,
+        });
+    }
+    Ok(checks)
+}
+
+/// Check every given day, print a table with the results and return the
+/// number of parts that failed (or couldn't be run)
+pub fn check(days: &[&Day]) -> usize {
+    let mut n_failed = 0;
+    println!(
+        "{:<5}{:<6}{:>16}{:>16}  Status",
+        "Day", "Part", "Expected", "Actual"
+    );
+    for day in days {
+        let checks = match check_day(day) {
+            Ok(checks) => checks,
+            Err(error) => {
+                n_failed += 1;
+                println!("{:02}   error: {}", day.number, error);
+                continue;
+            }
+        };
+        for check in checks.iter() {
+            if check.status == Status::Fail {
+                n_failed += 1;
+            }
+            println!(
+                "{:<5}{:<6}{:>16}{:>16}  {}",
+                format!("{:02}", day.number),
+                check.part,
+                check.expected.as_deref().unwrap_or("-"),
+                check.actual.as_deref().unwrap_or("-"),
+                check.status.label()
+            );
+        }
+    }
+    n_failed
+}
@@ -0,0 +1,375 @@
+//! Parse the command line arguments of the runner
+
+use std::path::PathBuf;
+
+pub const USAGE: &str = "\
+Usage: aoc run <DAY|all> [--part <1|2>] [--input <FILE|-> | --example <N>]
+               [--format <text|json>]
+       aoc check <DAY|all>
+       aoc bench <DAY|all> [OPTIONS]
+
+Run the solutions to the Advent of Code 2023 puzzles, check their answers
+against the expected ones (in `data/answers`), or benchmark them.
+
+Arguments:
+  <DAY|all>                Number of the day to run, or `all` to run every day
+
+Options:
+  --part <1|2>             Run only one part of the puzzle
+  --input <FILE|->         Read the input from FILE, or from the standard
+                           input if `-` is given, instead of `data/input`
+  --example <N>            Read the input from the N-th example of the day
+                           (`data/test_input_N`)
+  --format <text|json>     Print the answers and timings as free text, or as
+                           JSON [default: text]
+  -h, --help               Print this help message
+
+Benchmark options:
+  --iterations <N>         Number of times each benchmark is run [default: 100]
+  --save-baseline <FILE>   Save the results as a baseline in FILE
+  --baseline <FILE>        Compare the results with the baseline saved in FILE
+  --threshold <PERCENT>    Increase of the median time over the baseline that
+                           is reported as a regression [default: 10]";
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_run() {
+        let command = parse(args("run 7 --part 2")).unwrap();
+        let expected = Command::Run {
+            days: Selection::Day(7),
+            part: Some(2),
+            source: Source::Default,
+            format: Format::Text,
+        };
+        assert_eq!(command, expected);
+        let command = parse(args("run all")).unwrap();
+        let expected = Command::Run {
+            days: Selection::All,
+            part: None,
+            source: Source::Default,
+            format: Format::Text,
+        };
+        assert_eq!(command, expected);
+    }
+
+    #[test]
+    fn test_parse_run_format() {
+        let command = parse(args("run all --format json")).unwrap();
+        let expected = Command::Run {
+            days: Selection::All,
+            part: None,
+            source: Source::Default,
+            format: Format::Json,
+        };
+        assert_eq!(command, expected);
+        let command = parse(args("run 7 --format text")).unwrap();
+        let expected = Command::Run {
+            days: Selection::Day(7),
+            part: None,
+            source: Source::Default,
+            format: Format::Text,
+        };
+        assert_eq!(command, expected);
+    }
+
+    #[test]
+    fn test_parse_run_source() {
+        let command = parse(args("run 7 --input other/input --part 1")).unwrap();
+        let expected = Command::Run {
+            days: Selection::Day(7),
+            part: Some(1),
+            source: Source::Path(PathBuf::from("other/input")),
+            format: Format::Text,
+        };
+        assert_eq!(command, expected);
+        let command = parse(args("run 7 --input -")).unwrap();
+        let expected = Command::Run {
+            days: Selection::Day(7),
+            part: None,
+            source: Source::Stdin,
+            format: Format::Text,
+        };
+        assert_eq!(command, expected);
+        let command = parse(args("run 8 --example 3")).unwrap();
+        let expected = Command::Run {
+            days: Selection::Day(8),
+            part: None,
+            source: Source::Example(3),
+            format: Format::Text,
+        };
+        assert_eq!(command, expected);
+    }
+
+    #[test]
+    fn test_parse_check() {
+        let command = parse(args("check all")).unwrap();
+        let expected = Command::Check {
+            days: Selection::All,
+        };
+        assert_eq!(command, expected);
+        assert!(parse(args("check 7 --part 1")).is_err());
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        let command = parse(args("bench all")).unwrap();
+        let expected = Command::Bench {
+            days: Selection::All,
+            options: BenchOptions::default(),
+        };
+        assert_eq!(command, expected);
+        let command = parse(args(
+            "bench 6 --iterations 10 --baseline old --save-baseline new --threshold 5.5",
+        ))
+        .unwrap();
+        let expected = Command::Bench {
+            days: Selection::Day(6),
+            options: BenchOptions {
+                iterations: 10,
+                baseline: Some(PathBuf::from("old")),
+                save_baseline: Some(PathBuf::from("new")),
+                threshold: 5.5,
+            },
+        };
+        assert_eq!(command, expected);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(parse(args("")).is_err());
+        assert!(parse(args("fly 7")).is_err());
+        assert!(parse(args("run")).is_err());
+        assert!(parse(args("run seven")).is_err());
+        assert!(parse(args("run 7 --part 3")).is_err());
+        assert!(parse(args("run 7 --part")).is_err());
+        assert!(parse(args("run 7 --unknown")).is_err());
+        assert!(parse(args("run 7 --iterations 10")).is_err());
+        assert!(parse(args("run all --input other/input")).is_err());
+        assert!(parse(args("run all --example 1")).is_err());
+        assert!(parse(args("run 7 --example 0")).is_err());
+        assert!(parse(args("run 7 --example 1 --input -")).is_err());
+        assert!(parse(args("check 7 --example 1")).is_err());
+        assert!(parse(args("run 7 --format yaml")).is_err());
+        assert!(parse(args("run 7 --format")).is_err());
+        assert!(parse(args("bench 7 --part 1")).is_err());
+        assert!(parse(args("bench 7 --iterations 0")).is_err());
+        assert!(parse(args("bench 7 --threshold fast")).is_err());
+        assert!(parse(args("bench 7 --baseline")).is_err());
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Selection {
+    All,
+    Day(u8),
+}
+
+/// Where the input of a day is read from
+#[derive(Debug, PartialEq)]
+pub enum Source {
+    /// The puzzle input of the day, in `data/input`
+    Default,
+    Path(PathBuf),
+    Stdin,
+    /// One of the examples of the day, starting from 1
+    Example(u32),
+}
+
+/// Format in which the answers are printed
+#[derive(Debug, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+/// Settings of the benchmarks
+#[derive(Debug, PartialEq)]
+pub struct BenchOptions {
+    pub iterations: usize,
+    /// File with a baseline to compare the results with
+    pub baseline: Option<PathBuf>,
+    /// File where the results will be saved as a baseline
+    pub save_baseline: Option<PathBuf>,
+    /// Increase of the median time (in %) reported as a regression
+    pub threshold: f64,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions {
+            iterations: 100,
+            baseline: None,
+            save_baseline: None,
+            threshold: 10.0,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run {
+        days: Selection,
+        part: Option<u8>,
+        source: Source,
+        format: Format,
+    },
+    Check {
+        days: Selection,
+    },
+    Bench {
+        days: Selection,
+        options: BenchOptions,
+    },
+    Help,
+}
+
+/// Parse the command line arguments (without the name of the program)
+pub fn parse(args: Vec<String>) -> Result<Command, String> {
+    let mut args = args.into_iter();
+    let command = match args.next() {
+        Some(command) => command,
+        None => return Err(String::from("missing command")),
+    };
+    let bench = match command.as_str() {
+        "run" | "check" => false,
+        "bench" => true,
+        "-h" | "--help" | "help" => return Ok(Command::Help),
+        _ => return Err(format!("unknown command '{}'", command)),
+    };
+    let days = match args.next() {
+        Some(day) => parse_selection(&day)?,
+        None => return Err(format!("missing day to {}", command)),
+    };
+    let mut part = None;
+    let mut source = Source::Default;
+    let mut format = Format::Text;
+    let mut options = BenchOptions::default();
+    while let Some(arg) = args.next() {
+        match (arg.as_str(), bench) {
+            ("--part", false) if command == "run" => part = Some(parse_part(args.next())?),
+            ("--input" | "--example", false) if command == "run" => {
+                if source != Source::Default {
+                    return Err(String::from("--input and --example can't be used together"));
+                }
+                source = parse_source(&arg, args.next())?;
+            }
+            ("--format", false) if command == "run" => format = parse_format(args.next())?,
+            ("--iterations", true) => options.iterations = parse_iterations(args.next())?,
+            ("--baseline", true) => options.baseline = Some(parse_path(&arg, args.next())?),
+            ("--save-baseline", true) => {
+                options.save_baseline = Some(parse_path(&arg, args.next())?)
+            }
+            ("--threshold", true) => options.threshold = parse_threshold(args.next())?,
+            ("-h" | "--help", _) => return Ok(Command::Help),
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+    if bench {
+        return Ok(Command::Bench { days, options });
+    }
+    if command == "check" {
+        return Ok(Command::Check { days });
+    }
+    if source != Source::Default && days == Selection::All {
+        return Err(format!("{} requires a single day", describe(&source)));
+    }
+    Ok(Command::Run {
+        days,
+        part,
+        source,
+        format,
+    })
+}
+
+fn parse_selection(day: &str) -> Result<Selection, String> {
+    if day == "all" {
+        return Ok(Selection::All);
+    }
+    match day.parse() {
+        Ok(number) => Ok(Selection::Day(number)),
+        Err(_) => Err(format!("invalid day '{}'", day)),
+    }
+}
+
+fn parse_part(part: Option<String>) -> Result<u8, String> {
+    match part.as_deref() {
+        Some("1") => Ok(1),
+        Some("2") => Ok(2),
+        Some(part) => Err(format!("invalid part '{}', expected 1 or 2", part)),
+        None => Err(String::from("missing value for --part")),
+    }
+}
+
+fn parse_format(format: Option<String>) -> Result<Format, String> {
+    match format.as_deref() {
+        Some("text") => Ok(Format::Text),
+        Some("json") => Ok(Format::Json),
+        Some(format) => Err(format!(
+            "invalid format '{}', expected text or json",
+            format
+        )),
+        None => Err(String::from("missing value for --format")),
+    }
+}
+
+fn parse_iterations(iterations: Option<String>) -> Result<usize, String> {
+    match iterations.as_deref().map(str::parse) {
+        Some(Ok(iterations)) if iterations > 0 => Ok(iterations),
+        Some(_) => Err(String::from(
+            "invalid value for --iterations, expected a positive integer",
+        )),
+        None => Err(String::from("missing value for --iterations")),
+    }
+}
+
+fn parse_threshold(threshold: Option<String>) -> Result<f64, String> {
+    match threshold.as_deref().map(str::parse) {
+        Some(Ok(threshold)) => Ok(threshold),
+        Some(Err(_)) => Err(String::from(
+            "invalid value for --threshold, expected a number",
+        )),
+        None => Err(String::from("missing value for --threshold")),
+    }
+}
+
+fn parse_path(option: &str, path: Option<String>) -> Result<PathBuf, String> {
+    match path {
+        Some(path) => Ok(PathBuf::from(path)),
+        None => Err(format!("missing value for {}", option)),
+    }
+}
+
+fn parse_source(option: &str, value: Option<String>) -> Result<Source, String> {
+    let value = match value {
+        Some(value) => value,
+        None => return Err(format!("missing value for {}", option)),
+    };
+    if option == "--input" {
+        if value == "-" {
+            return Ok(Source::Stdin);
+        }
+        return Ok(Source::Path(PathBuf::from(value)));
+    }
+    match value.parse() {
+        Ok(number) if number > 0 => Ok(Source::Example(number)),
+        _ => Err(format!(
+            "invalid example '{}', expected a positive integer",
+            value
+        )),
+    }
+}
+
+/// Name of the option used to select the source
+fn describe(source: &Source) -> &'static str {
+    match source {
+        Source::Example(_) => "--example",
+        _ => "--input",
+    }
+}
@@ -0,0 +1,141 @@
+//! Registry of the solutions to every day of the calendar
+use aoc_common::{ParseError, RawInput, Solution};
+use std::any::Any;
+use std::path::PathBuf;
+
+#[cfg(test)]
+mod tests {
+    use crate::days::*;
+
+    #[test]
+    fn test_example() {
+        let day = get(8).unwrap();
+        assert!(day.example(1).ends_with("day-08/data/test_input_1"));
+        assert!(day.example(3).ends_with("day-08/data/test_input_3"));
+        let day = get(7).unwrap();
+        assert!(day.example(1).ends_with("day-07/data/test_input"));
+        assert!(day.example(2).ends_with("day-07/data/test_input_2"));
+    }
+}
+
+/// Function that parses the input of a day, hiding the type of the result
+type Parser = fn(&RawInput) -> Result<Box<dyn Any>, ParseError>;
+
+/// Function that solves one part of a puzzle given its parsed input
+type Solver = fn(&dyn Any) -> Option<String>;
+
+/// Solutions to the puzzle of a single day
+///
+/// Every day parses its input into a different type, so the registry erases
+/// it: the parsed input is boxed as [`Any`] and the solvers downcast it back
+/// to the type of the [`Solution`] they were created from.
+pub struct Day {
+    pub number: u8,
+    /// Directory of the day crate, relative to the root of the repository
+    pub dir: &'static str,
+    parser: Parser,
+    part1: Solver,
+    part2: Solver,
+}
+
+impl Day {
+    /// Register the solution to the puzzle of a day
+    const fn new<S: Solution + 'static>(number: u8, dir: &'static str) -> Self {
+        Self {
+            number,
+            dir,
+            parser: parse::<S>,
+            part1: solve_part1::<S>,
+            part2: solve_part2::<S>,
+        }
+    }
+
+    /// Return the path to a file in the data directory of the day
+    fn data(&self, fname: &str) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(self.dir)
+            .join("data")
+            .join(fname)
+    }
+
+    /// Return the path to the puzzle input of the day
+    pub fn input(&self) -> PathBuf {
+        self.data("input")
+    }
+
+    /// Return the path to the N-th example input of the day
+    ///
+    /// Examples are stored in `data/test_input_N`. Days with a single example
+    /// store it in `data/test_input`, which is used as the first one.
+    pub fn example(&self, number: u32) -> PathBuf {
+        let path = self.data(&format!("test_input_{}", number));
+        if number == 1 && !path.exists() {
+            return self.data("test_input");
+        }
+        path
+    }
+
+    /// Return the path to the file with the expected answers of the day
+    pub fn answers(&self) -> PathBuf {
+        self.data("answers")
+    }
+
+    /// Parse the input of the day
+    pub fn parse(&self, input: &RawInput) -> Result<Box<dyn Any>, ParseError> {
+        (self.parser)(input)
+    }
+
+    /// Solve one part of the puzzle, returning `None` if it hasn't been solved
+    /// or the input has no answer to it
+    ///
+    /// The `input` must have been returned by [`Day::parse`] of this same day.
+    pub fn solve(&self, part: u8, input: &dyn Any) -> Option<String> {
+        match part {
+            1 => (self.part1)(input),
+            2 => (self.part2)(input),
+            _ => None,
+        }
+    }
+}
+
+fn parse<S: Solution + 'static>(input: &RawInput) -> Result<Box<dyn Any>, ParseError> {
+    Ok(Box::new(S::parse(input)?))
+}
+
+fn downcast<S: Solution + 'static>(input: &dyn Any) -> &S::Input {
+    input
+        .downcast_ref()
+        .expect("input should have been parsed by the same day")
+}
+
+fn solve_part1<S: Solution + 'static>(input: &dyn Any) -> Option<String> {
+    S::part1(downcast::<S>(input)).map(|answer| answer.to_string())
+}
+
+fn solve_part2<S: Solution + 'static>(input: &dyn Any) -> Option<String> {
+    S::part2(downcast::<S>(input)).map(|answer| answer.to_string())
+}
+
+pub static DAYS: [Day; 15] = [
+    Day::new::<day_01::Day01>(1, "day-01"),
+    Day::new::<day_02::Day02>(2, "day-02"),
+    Day::new::<day_03::Day03>(3, "day-03"),
+    Day::new::<day_04::Day04>(4, "day-04"),
+    Day::new::<day_05::Day05>(5, "day-05"),
+    Day::new::<day_06::Day06>(6, "day-06"),
+    Day::new::<day_07::Day07>(7, "day-07"),
+    Day::new::<day_08::Day08>(8, "day-08"),
+    Day::new::<day_09::Day09>(9, "day-09"),
+    Day::new::<day_10::Day10>(10, "day-10"),
+    Day::new::<day_11::Day11>(11, "day-11"),
+    Day::new::<day_12::Day12>(12, "day-12"),
+    Day::new::<day_13::Day13>(13, "day-13"),
+    Day::new::<day_14::Day14>(14, "day-14"),
+    Day::new::<day_15::Day15>(15, "day-15"),
+];
+
+/// Return the registered day with the given number
+pub fn get(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
@@ -0,0 +1,210 @@
+mod bench;
+mod check;
+mod cli;
+mod days;
+mod report;
+
+use aoc_common::{Error, RawInput};
+use cli::{BenchOptions, Command, Format, Selection, Source};
+use days::Day;
+use report::{DayRun, PartRun};
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_panic_message() {
+        let payload = panic::catch_unwind(|| panic!("no node {}", "AAA")).unwrap_err();
+        assert_eq!(panic_message(payload.as_ref()), "no node AAA");
+        let payload = panic::catch_unwind(|| panic!("no node")).unwrap_err();
+        assert_eq!(panic_message(payload.as_ref()), "no node");
+        let payload = panic::catch_unwind(|| panic::panic_any(1)).unwrap_err();
+        assert_eq!(panic_message(payload.as_ref()), "unknown error");
+    }
+
+    #[test]
+    fn test_run_examples() {
+        for day in days::DAYS.iter() {
+            let mut number = 1;
+            while day.example(number).exists() {
+                let result = run_day(day, None, &Source::Example(number));
+                let run = result.unwrap_or_else(|error| {
+                    panic!("day {} example {}: {}", day.number, number, error)
+                });
+                assert_eq!(run.parts.len(), 2);
+                number += 1;
+            }
+        }
+    }
+}
+
+fn read_input(day: &Day, source: &Source) -> Result<RawInput, Error> {
+    let input = match source {
+        Source::Default => RawInput::from_path(day.input())?,
+        Source::Path(path) => RawInput::from_path(path)?,
+        Source::Stdin => RawInput::from_stdin()?,
+        Source::Example(number) => RawInput::from_path(day.example(*number))?,
+    };
+    Ok(input)
+}
+
+/// Return the message a panic was raised with
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    match payload.downcast_ref::<&str>() {
+        Some(message) => message,
+        None => match payload.downcast_ref::<String>() {
+            Some(message) => message,
+            None => "unknown error",
+        },
+    }
+}
+
+fn run_day(day: &Day, part: Option<u8>, source: &Source) -> Result<DayRun, String> {
+    let input = read_input(day, source).map_err(|error| error.to_string())?;
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let now = Instant::now();
+    let parsed = day.parse(&input).map_err(|error| error.to_string())?;
+    let parse_time = now.elapsed();
+    let mut run = DayRun {
+        day: day.number,
+        input: String::from(input.name()),
+        input_hash: report::fnv1a(input.content().as_bytes()),
+        parse_time,
+        parts: vec![],
+    };
+    for part in parts {
+        let now = Instant::now();
+        // A solver that panics only makes its day fail, not the whole run
+        let answer = panic::catch_unwind(AssertUnwindSafe(|| day.solve(part, parsed.as_ref())))
+            .map_err(|payload| format!("part {} panicked: {}", part, panic_message(&*payload)))?;
+        let solve_time = now.elapsed();
+        run.parts.push(PartRun {
+            part,
+            answer,
+            solve_time,
+        });
+    }
+    Ok(run)
+}
+
+fn run(days: Selection, part: Option<u8>, source: Source, format: Format) -> Result<(), String> {
+    let single = matches!(days, Selection::Day(_));
+    let days = select(days)?;
+    let mut runs = vec![];
+    let mut total = Duration::ZERO;
+    let mut errors = vec![];
+    for day in days {
+        match run_day(day, part, &source) {
+            Ok(run) => {
+                if format == Format::Text {
+                    report::print_text(&run);
+                }
+                total += run.total_time();
+                runs.push(Ok(run));
+            }
+            Err(error) => {
+                if format == Format::Text && !single {
+                    eprintln!("Day {:02}: {}", day.number, error);
+                }
+                errors.push(error.to_string());
+                runs.push(Err((day.number, error.to_string())));
+            }
+        }
+    }
+    match format {
+        Format::Text if !single => println!("Total elapsed: {:.2?}", total),
+        Format::Text => (),
+        Format::Json => println!("{}", report::to_json(&runs)),
+    }
+    match errors.len() {
+        0 => Ok(()),
+        1 if single => Err(errors.remove(0)),
+        n_failed => Err(format!("{} days failed to run", n_failed)),
+    }
+}
+
+/// Return the registered days that were selected
+fn select(days: Selection) -> Result<Vec<&'static Day>, String> {
+    match days {
+        Selection::Day(number) => match days::get(number) {
+            Some(day) => Ok(vec![day]),
+            None => Err(format!("no solution available for day {}", number)),
+        },
+        Selection::All => Ok(days::DAYS.iter().collect()),
+    }
+}
+
+fn run_check(days: Selection) -> Result<(), String> {
+    let days = select(days)?;
+    let n_failed = check::check(&days);
+    if n_failed > 0 {
+        return Err(format!("{} answers didn't match", n_failed));
+    }
+    Ok(())
+}
+
+fn run_bench(days: Selection, options: BenchOptions) -> Result<(), String> {
+    let days = select(days)?;
+    let baseline = match &options.baseline {
+        Some(path) => Some(bench::load_baseline(path).map_err(|e| e.to_string())?),
+        None => None,
+    };
+    let mut measurements = vec![];
+    for day in days {
+        match bench::bench_day(day, options.iterations) {
+            Ok(day_measurements) => measurements.extend(day_measurements),
+            Err(error) => return Err(format!("day {:02}: {}", day.number, error)),
+        }
+    }
+    let n_regressions = bench::report(&measurements, baseline.as_ref(), options.threshold);
+    if let Some(path) = &options.save_baseline {
+        if let Err(error) = bench::save_baseline(path, &measurements) {
+            return Err(format!("couldn't write {}: {}", path.display(), error));
+        }
+        println!("Baseline saved to {}", path.display());
+    }
+    if n_regressions > 0 {
+        return Err(format!("{} benchmarks regressed", n_regressions));
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    // Panics of the solvers are reported as errors of their day, so only
+    // show where they happened, without a backtrace
+    panic::set_hook(Box::new(|info| eprintln!("{}", info)));
+    let args = std::env::args().skip(1).collect();
+    let result = match cli::parse(args) {
+        Ok(Command::Help) => {
+            println!("{}", cli::USAGE);
+            Ok(())
+        }
+        Ok(Command::Run {
+            days,
+            part,
+            source,
+            format,
+        }) => run(days, part, source, format),
+        Ok(Command::Check { days }) => run_check(days),
+        Ok(Command::Bench { days, options }) => run_bench(days, options),
+        Err(error) => {
+            eprintln!("error: {}\n\n{}", error, cli::USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
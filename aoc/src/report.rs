@@ -0,0 +1,191 @@
+//! Report the answers and timings of the days that were run
+use std::fmt::Write;
+use std::time::Duration;
+
+#[cfg(test)]
+mod tests {
+    use crate::report::*;
+
+    fn example_run() -> DayRun {
+        DayRun {
+            day: 7,
+            input: String::from("data/\"input\""),
+            input_hash: fnv1a("32T3K 765\n".as_bytes()),
+            parse_time: Duration::from_nanos(1500),
+            parts: vec![
+                PartRun {
+                    part: 1,
+                    answer: Some(String::from("6440")),
+                    solve_time: Duration::from_nanos(300),
+                },
+                PartRun {
+                    part: 2,
+                    answer: None,
+                    solve_time: Duration::from_nanos(10),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_fnv1a() {
+        assert_eq!(fnv1a(b""), 0xcbf29ce484222325);
+        assert_eq!(fnv1a(b"a"), 0xaf63dc4c8601ec8c);
+        assert_eq!(fnv1a(b"foobar"), 0x85944171f73967e8);
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("abc"), "\"abc\"");
+        assert_eq!(escape("a\"b\\c\nd\u{1}"), "\"a\\\"b\\\\c\\nd\\u0001\"");
+    }
+
+    #[test]
+    fn test_to_json() {
+        let runs = vec![
+            Ok(example_run()),
+            Err((8, String::from("couldn't read data/input"))),
+        ];
+        let expected = format!(
+            "[\n  {{\"day\": 7, \"input\": \"data/\\\"input\\\"\", \"input_hash\": \"{:016x}\", \
+             \"parse_ns\": 1500, \"parts\": [{{\"part\": 1, \"answer\": \"6440\", \"solve_ns\": 300}}, \
+             {{\"part\": 2, \"answer\": null, \"solve_ns\": null}}]}},\n  \
+             {{\"day\": 8, \"error\": \"couldn't read data/input\"}}\n]",
+            fnv1a("32T3K 765\n".as_bytes())
+        );
+        assert_eq!(to_json(&runs), expected);
+        assert_eq!(to_json(&[]), "[]");
+    }
+
+    #[test]
+    fn test_total_time() {
+        let run = example_run();
+        assert_eq!(run.total_time(), Duration::from_nanos(1800));
+    }
+}
+
+/// Answer to one part of a puzzle and the time it took to solve it
+#[derive(Debug)]
+pub struct PartRun {
+    pub part: u8,
+    /// Answer to the part, or `None` if it hasn't been solved
+    pub answer: Option<String>,
+    pub solve_time: Duration,
+}
+
+/// Answers and timings of a day
+#[derive(Debug)]
+pub struct DayRun {
+    pub day: u8,
+    /// Name of the input the day was run with
+    pub input: String,
+    /// FNV-1a hash of the content of the input
+    pub input_hash: u64,
+    pub parse_time: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+impl DayRun {
+    /// Time spent parsing the input and solving the parts that were solved
+    pub fn total_time(&self) -> Duration {
+        let solve_time: Duration = self
+            .parts
+            .iter()
+            .filter(|part| part.answer.is_some())
+            .map(|part| part.solve_time)
+            .sum();
+        self.parse_time + solve_time
+    }
+}
+
+/// Compute the 64 bits FNV-1a hash of a sequence of bytes
+///
+/// It's not a cryptographic hash, but it's enough to tell apart the inputs a
+/// day has been run with.
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+/// Print the answers and timings of a day as free text
+pub fn print_text(run: &DayRun) {
+    println!("Day {:02}, parsing ({:.2?})", run.day, run.parse_time);
+    for part in run.parts.iter() {
+        match &part.answer {
+            Some(answer) => println!(
+                "Day {:02}, part {}: {} ({:.2?})",
+                run.day, part.part, answer, part.solve_time
+            ),
+            None => println!("Day {:02}, part {}: not solved", run.day, part.part),
+        }
+    }
+}
+
+/// Quote a string as a JSON string
+fn escape(string: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in string.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn part_to_json(part: &PartRun) -> String {
+    match &part.answer {
+        Some(answer) => format!(
+            "{{\"part\": {}, \"answer\": {}, \"solve_ns\": {}}}",
+            part.part,
+            escape(answer),
+            part.solve_time.as_nanos()
+        ),
+        None => format!(
+            "{{\"part\": {}, \"answer\": null, \"solve_ns\": null}}",
+            part.part
+        ),
+    }
+}
+
+fn day_to_json(run: &DayRun) -> String {
+    let parts: Vec<String> = run.parts.iter().map(part_to_json).collect();
+    format!(
+        "{{\"day\": {}, \"input\": {}, \"input_hash\": \"{:016x}\", \"parse_ns\": {}, \"parts\": [{}]}}",
+        run.day,
+        escape(&run.input),
+        run.input_hash,
+        run.parse_time.as_nanos(),
+        parts.join(", ")
+    )
+}
+
+/// Format the runs of several days as a JSON array, with one day per line
+///
+/// Days that couldn't be run are given by their number and the error they
+/// raised. Answers are written as strings, since they may not fit in the
+/// integers that JSON parsers support. Times are given in nanoseconds.
+pub fn to_json(runs: &[Result<DayRun, (u8, String)>]) -> String {
+    let days: Vec<String> = runs
+        .iter()
+        .map(|run| match run {
+            Ok(run) => day_to_json(run),
+            Err((day, error)) => format!("{{\"day\": {}, \"error\": {}}}", day, escape(error)),
+        })
+        .map(|day| format!("  {}", day))
+        .collect();
+    if days.is_empty() {
+        return String::from("[]");
+    }
+    format!("[\n{}\n]", days.join(",\n"))
+}
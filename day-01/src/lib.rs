@@ -0,0 +1,109 @@
+//! Solution to day 1 of Advent of Code 2023: Trebuchet?!
+use aoc_common::{ParseError, RawInput, Solution};
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_part1() {
+        let fname = String::from("data/test_input");
+        let input = Day01::parse_file(&fname).unwrap();
+        let result = Day01::part1(&input);
+        assert_eq!(result, Some(142));
+        // The example of the second part has lines without digits
+        let fname = String::from("data/test_input_2");
+        let input = Day01::parse_file(&fname).unwrap();
+        assert_eq!(Day01::part1(&input), None);
+    }
+
+    #[test]
+    fn test_part2() {
+        let fname = String::from("data/test_input_2");
+        let input = Day01::parse_file(&fname).unwrap();
+        let result = Day01::part2(&input);
+        assert_eq!(result, Some(281));
+    }
+    #[test]
+    fn test_part2_with_shared_chars() {
+        // Test when numbers share chars
+        let fname = String::from("data/test_input_3");
+        let input = Day01::parse_file(&fname).unwrap();
+        let result = Day01::part2(&input);
+        assert_eq!(result, Some(281 + 82));
+    }
+}
+
+/// Solution to the puzzle of day 1
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<String>;
+    type Answer = u32;
+
+    fn parse(input: &RawInput) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(|line| String::from(line.text)).collect())
+    }
+
+    fn part1(lines: &Self::Input) -> Option<u32> {
+        solve_part1(lines)
+    }
+
+    fn part2(lines: &Self::Input) -> Option<u32> {
+        solve_part2(lines)
+    }
+}
+
+/// Return the calibration value given by the first and last digits of a
+/// line, or `None` if there are no digits
+fn calibration_value(digits: &[char]) -> Option<u32> {
+    let first = digits.first()?.to_digit(10)?;
+    let last = digits.last()?.to_digit(10)?;
+    Some(10 * first + last)
+}
+
+fn solve_part1(lines: &[String]) -> Option<u32> {
+    let mut result = 0;
+
+    for line in lines.iter() {
+        let values: Vec<char> = line.chars().filter(|x| x.is_ascii_digit()).collect();
+        result += calibration_value(&values)?;
+    }
+    Some(result)
+}
+
+fn solve_part2(lines: &[String]) -> Option<u32> {
+    let mut result = 0;
+
+    for line in lines.iter() {
+        let values = get_numbers_in_line(line);
+        result += calibration_value(&values)?;
+    }
+    Some(result)
+}
+
+/// Return the digits found in a line, including the ones spelled out as words
+///
+/// Spelled out digits are returned as their numeric character, e.g. `"one"`
+/// is returned as `'1'`. Words that share characters (like `"twone"`) count
+/// as two digits.
+pub fn get_numbers_in_line(line: &str) -> Vec<char> {
+    let numbers_as_str = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+    let mut numbers = Vec::new();
+
+    for (i, character) in line.char_indices() {
+        // Check numeric character
+        if character.is_ascii_digit() {
+            numbers.push(character);
+        };
+        // Check for numbers as words
+        for (j, num_str) in numbers_as_str.iter().enumerate() {
+            if line[i..].starts_with(num_str) {
+                numbers.push(char::from_digit(j as u32 + 1, 10).unwrap());
+            };
+        }
+    }
+    numbers
+}
@@ -0,0 +1,264 @@
+//! Solution to day 2 of Advent of Code 2023: Cube Conundrum
+use aoc_common::{Line, ParseError, RawInput, Solution};
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_set() {
+        let set = Set {
+            red: 3,
+            green: 4,
+            blue: 5,
+        };
+        assert!(set.is_possible(12, 14, 15));
+        assert!(!set.is_possible(1, 14, 15));
+        assert!(!set.is_possible(12, 1, 15));
+        assert!(!set.is_possible(12, 14, 1));
+    }
+
+    #[test]
+    fn test_game() {
+        let set1 = Set {
+            red: 3,
+            green: 4,
+            blue: 5,
+        };
+        let set2 = Set {
+            red: 5,
+            green: 6,
+            blue: 7,
+        };
+        let game = Game {
+            index: 0,
+            sets: vec![set1, set2],
+        };
+        assert!(game.is_possible(12, 14, 15));
+        assert!(game.is_possible(5, 6, 7));
+        assert!(!game.is_possible(1, 14, 15));
+        assert!(!game.is_possible(12, 1, 15));
+        assert!(!game.is_possible(12, 14, 1));
+        assert!(!game.is_possible(1, 1, 1));
+        assert!(!game.is_possible(5, 5, 5));
+    }
+
+    #[test]
+    fn test_game_minimum_set() {
+        let set1 = Set {
+            red: 3,
+            green: 4,
+            blue: 5,
+        };
+        let set2 = Set {
+            red: 5,
+            green: 2,
+            blue: 7,
+        };
+        let game = Game {
+            index: 0,
+            sets: vec![set1, set2],
+        };
+        let minimum_set = game.minimum_set();
+        assert_eq!(minimum_set.red, 5);
+        assert_eq!(minimum_set.green, 4);
+        assert_eq!(minimum_set.blue, 7);
+    }
+
+    #[test]
+    fn test_parse_line() {
+        let input = RawInput::from("Game 12: 3 blue, 4 red; 1 red, 2 green");
+        let game = parse_line(&input.lines().next().unwrap()).unwrap();
+        assert_eq!(game.index, 12);
+        assert_eq!(game.sets.len(), 2);
+        assert_eq!(game.sets[0].blue, 3);
+        assert_eq!(game.sets[0].red, 4);
+        assert_eq!(game.sets[1].green, 2);
+    }
+
+    #[test]
+    fn test_parse_line_errors() {
+        let input = RawInput::from(
+            "Game 1 3 blue\nGame x: 3 blue\nGame 3: 3 purple\nGame 4: 3 blue, 4\nGame 5: 3\r blue",
+        );
+        let errors: Vec<ParseError> = input
+            .lines()
+            .map(|line| parse_line(&line).unwrap_err())
+            .collect();
+        assert_eq!(errors[0].column, 14);
+        assert_eq!(errors[0].token, "");
+        assert_eq!((errors[1].line, errors[1].column), (2, 6));
+        assert_eq!(errors[1].token, "x");
+        assert_eq!((errors[2].line, errors[2].column), (3, 11));
+        assert_eq!(errors[2].token, "purple");
+        assert_eq!((errors[3].line, errors[3].column), (4, 17));
+        assert_eq!(errors[3].token, "4");
+        assert_eq!((errors[4].line, errors[4].column), (5, 9));
+        assert_eq!(errors[4].token, "3\r");
+    }
+
+    #[test]
+    fn test_part1() {
+        let fname = String::from("data/test_input");
+        let input = Day02::parse_file(&fname).unwrap();
+        let result = Day02::part1(&input);
+        assert_eq!(result, Some(8));
+    }
+
+    #[test]
+    fn test_part2() {
+        let fname = String::from("data/test_input");
+        let input = Day02::parse_file(&fname).unwrap();
+        let result = Day02::part2(&input);
+        assert_eq!(result, Some(2286));
+    }
+}
+
+/// Set of cubes revealed from the bag
+#[derive(Debug)]
+pub struct Set {
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+
+impl Set {
+    /// Check if the set is possible
+    ///
+    /// # Arguments
+    ///
+    /// * `red` - total ammount of red cubes in the bag
+    /// * `green` - total ammount of green cubes in the bag
+    /// * `blue` - total ammount of blue cubes in the bag
+    pub fn is_possible(&self, red: u32, green: u32, blue: u32) -> bool {
+        if self.red > red || self.green > green || self.blue > blue {
+            return false;
+        }
+        true
+    }
+}
+
+/// Game played with the bag of cubes
+#[derive(Debug)]
+pub struct Game {
+    pub index: u32,
+    pub sets: Vec<Set>,
+}
+
+impl Game {
+    /// Check if the game is possible
+    ///
+    /// # Arguments
+    ///
+    /// * `red` - total ammount of red cubes in the bag
+    /// * `green` - total ammount of green cubes in the bag
+    /// * `blue` - total ammount of blue cubes in the bag
+    pub fn is_possible(&self, red: u32, green: u32, blue: u32) -> bool {
+        for set in self.sets.iter() {
+            if !set.is_possible(red, green, blue) {
+                return false;
+            };
+        }
+        true
+    }
+
+    /// Return the minimum set of cubes needed to play the game
+    pub fn minimum_set(&self) -> Set {
+        let reds: Vec<u32> = self.sets.iter().map(|x| x.red).collect();
+        let blues: Vec<u32> = self.sets.iter().map(|x| x.blue).collect();
+        let greens: Vec<u32> = self.sets.iter().map(|x| x.green).collect();
+        Set {
+            red: reds.iter().max().unwrap().to_owned(),
+            green: greens.iter().max().unwrap().to_owned(),
+            blue: blues.iter().max().unwrap().to_owned(),
+        }
+    }
+}
+
+/// Solution to the puzzle of day 2
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+    type Answer = u32;
+
+    fn parse(input: &RawInput) -> Result<Self::Input, ParseError> {
+        input.lines().map(|line| parse_line(&line)).collect()
+    }
+
+    fn part1(games: &Self::Input) -> Option<u32> {
+        Some(solve_part1(games))
+    }
+
+    fn part2(games: &Self::Input) -> Option<u32> {
+        Some(solve_part2(games))
+    }
+}
+
+fn solve_part1(games: &[Game]) -> u32 {
+    let red_total = 12;
+    let blue_total = 13;
+    let green_total = 14;
+
+    let mut result = 0;
+    for game in games.iter() {
+        if game.is_possible(red_total, blue_total, green_total) {
+            result += game.index
+        }
+    }
+    result
+}
+
+fn solve_part2(games: &[Game]) -> u32 {
+    let mut result = 0;
+    for game in games.iter() {
+        let minimum_set = game.minimum_set();
+        let product = minimum_set.red * minimum_set.green * minimum_set.blue;
+        result += product;
+    }
+    result
+}
+
+/// Parse a game out of a line like `Game 1: 3 blue, 4 red; 1 red, 2 green`
+pub fn parse_line(line: &Line) -> Result<Game, ParseError> {
+    let (header, sets_str) = match line.text.split_once(':') {
+        Some(parts) => parts,
+        None => return Err(line.error_at_end("expected ':' after the game index")),
+    };
+    // Parse game index
+    let index_str = match header.strip_prefix("Game ") {
+        Some(index_str) => index_str.trim(),
+        None => return Err(line.error(header, "expected 'Game <index>'")),
+    };
+    let index = match index_str.parse::<u32>() {
+        Ok(index) => index,
+        Err(_) => return Err(line.error(index_str, "invalid game index")),
+    };
+    // Parse sets
+    let mut sets: Vec<Set> = Vec::new();
+    for set_str in sets_str.split(";") {
+        let mut red: u32 = 0;
+        let mut blue: u32 = 0;
+        let mut green: u32 = 0;
+        for tuple in set_str.split(",") {
+            let tuple = tuple.trim();
+            let (n_str, color) = match tuple.split_once(" ") {
+                Some(values) => values,
+                None => return Err(line.error(tuple, "expected '<number> <color>'")),
+            };
+            let n = match n_str.parse() {
+                Ok(n) => n,
+                Err(_) => return Err(line.error(n_str, "invalid number of cubes")),
+            };
+            match color {
+                "red" => red = n,
+                "blue" => blue = n,
+                "green" => green = n,
+                _ => return Err(line.error(color, "unknown color")),
+            }
+        }
+        let set = Set { red, blue, green };
+        sets.push(set);
+    }
+    Ok(Game { index, sets })
+}
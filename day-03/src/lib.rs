@@ -0,0 +1,234 @@
+//! Solution to day 3 of Advent of Code 2023: Gear Ratios
+use aoc_common::{ParseError, RawInput, Solution};
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_part1() {
+        let fname = String::from("data/test_input");
+        let input = Day03::parse_file(&fname).unwrap();
+        let result = Day03::part1(&input);
+        assert_eq!(result, Some(4361));
+    }
+
+    #[test]
+    fn test_part2() {
+        let fname = String::from("data/test_input");
+        let input = Day03::parse_file(&fname).unwrap();
+        let result = Day03::part2(&input);
+        assert_eq!(result, Some(467835));
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        let input = RawInput::from("467..114..\n...*..a...");
+        let error = parse_input(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 7));
+        assert_eq!(error.token, "a");
+        let input = RawInput::from("467..114..\n...*......\n..35..633");
+        let error = parse_input(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.message, "expected a row of 10 tiles");
+    }
+
+    #[test]
+    fn test_wide_schematic() {
+        let input = RawInput::from("467..114.\n...*....2\n..35...#.");
+        let array = parse_input(&input).unwrap();
+        assert_eq!(Day03::part1(&array), Some(467 + 35 + 2));
+        assert_eq!(Day03::part2(&array), Some(467 * 35));
+    }
+
+    #[test]
+    fn test_get_single_part_number() {
+        let mut array: Vec<Vec<u32>> =
+            vec![vec![99, 99, 99, 99, 99, 99], vec![99, 1, 2, 3, 99, 99]];
+        let part_number = get_single_part_number(&mut array, &1, &3);
+        assert_eq!(part_number, 123);
+    }
+}
+
+/// Parse the engine schematic into an array of numbers
+///
+/// Each character is converted through [`char_to_number`].
+pub fn parse_input(input: &RawInput) -> Result<Vec<Vec<u32>>, ParseError> {
+    let mut array: Vec<Vec<u32>> = Vec::new();
+    for line in input.lines() {
+        let mut row: Vec<u32> = Vec::new();
+        for (i, c) in line.text.char_indices() {
+            match char_to_number(&c) {
+                Some(value) => row.push(value),
+                None => {
+                    let token = &line.text[i..i + c.len_utf8()];
+                    return Err(line.error(
+                        token,
+                        "invalid character, expected a digit, '.' or a symbol",
+                    ));
+                }
+            }
+        }
+        if let Some(first) = array.first() {
+            if row.len() != first.len() {
+                let message = format!("expected a row of {} tiles", first.len());
+                return Err(line.error(line.text, &message));
+            }
+        }
+        array.push(row);
+    }
+    Ok(array)
+}
+
+/// Convert a character of the engine schematic into a number
+///
+/// Digits are converted to their value, gears (`*`) to 10, any other symbol
+/// to 11 and empty spaces (`.`) to 99. Returns `None` for characters that
+/// aren't digits, `.` or ASCII punctuation symbols.
+pub fn char_to_number(x: &char) -> Option<u32> {
+    match x {
+        '0'..='9' => x.to_digit(10),
+        '.' => Some(99),
+        '*' => Some(10),
+        _ if x.is_ascii_punctuation() => Some(11),
+        _ => None,
+    }
+}
+
+/// Return the gear ratios of every gear in the engine schematic
+///
+/// The part numbers that are read get overridden in the `array`, so each
+/// one of them is only counted once.
+pub fn get_gear_ratii(array: &mut [Vec<u32>]) -> Vec<u32> {
+    let mut gear_ratii: Vec<u32> = Vec::new();
+    for i in 0..array.len() {
+        for j in 0..array[i].len() {
+            if array[i][j] == 10 {
+                let part_numbers_for_symbol = get_part_numbers_for_symbol(array, &i, &j);
+                if part_numbers_for_symbol.len() == 2 {
+                    let gear_ratio = part_numbers_for_symbol.iter().product();
+                    gear_ratii.push(gear_ratio);
+                }
+            }
+        }
+    }
+    gear_ratii
+}
+
+/// Return every part number in the engine schematic
+///
+/// The part numbers that are read get overridden in the `array`, so each
+/// one of them is only counted once.
+pub fn get_part_numbers(array: &mut [Vec<u32>]) -> Vec<u32> {
+    let mut part_numbers: Vec<u32> = Vec::new();
+    for i in 0..array.len() {
+        for j in 0..array[i].len() {
+            if array[i][j] > 9 && array[i][j] != 99 {
+                let mut part_numbers_for_symbol = get_part_numbers_for_symbol(array, &i, &j);
+                part_numbers.append(&mut part_numbers_for_symbol);
+            }
+        }
+    }
+    part_numbers
+}
+
+fn get_part_numbers_for_symbol(array: &mut [Vec<u32>], i: &usize, j: &usize) -> Vec<u32> {
+    let n_rows = array.len();
+    let n_cols = array[0].len();
+    let mut part_numbers: Vec<u32> = Vec::new();
+    for di in -1..1 + 1 {
+        for dj in -1..1 + 1 {
+            // Ignore the center (the symbol itself)
+            if di == 0 && dj == 0 {
+                continue;
+            }
+            // Ignore elements outside the array
+            if *i == 0 && di == -1 {
+                continue;
+            }
+            if *j == 0 && dj == -1 {
+                continue;
+            }
+            if *i + 1 == n_rows && di == 1 {
+                continue;
+            }
+            if *j + 1 == n_cols && dj == 1 {
+                continue;
+            }
+            // Get part number
+            let row_index = (*i as i32 + di) as usize;
+            let col_index = (*j as i32 + dj) as usize;
+            if array[row_index][col_index] < 10 {
+                let part_number = get_single_part_number(array, &row_index, &col_index);
+                part_numbers.push(part_number);
+            }
+        }
+    }
+    part_numbers
+}
+
+fn get_single_part_number(array: &mut [Vec<u32>], row_index: &usize, col_index: &usize) -> u32 {
+    let mut start: usize = *col_index;
+    let mut end: usize = *col_index;
+    let row = &mut array[*row_index];
+    loop {
+        if start == 0 {
+            break;
+        }
+        if row[start - 1] < 10 {
+            start -= 1
+        } else {
+            break;
+        }
+    }
+    loop {
+        if end == row.len() - 1 {
+            break;
+        }
+        if row[end + 1] < 10 {
+            end += 1
+        } else {
+            break;
+        }
+    }
+    let mut part_number = String::from("");
+    for digit in array[*row_index][start..end + 1].iter_mut() {
+        part_number.push(char::from_digit(*digit, 10).unwrap());
+        *digit = 99; // override so we don't read this part number again
+    }
+    part_number.parse().unwrap()
+}
+
+/// Solution to the puzzle of day 3
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Vec<u32>>;
+    type Answer = u32;
+
+    fn parse(input: &RawInput) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(array: &Self::Input) -> Option<u32> {
+        Some(solve_part1(array))
+    }
+
+    fn part2(array: &Self::Input) -> Option<u32> {
+        Some(solve_part2(array))
+    }
+}
+
+fn solve_part1(array: &[Vec<u32>]) -> u32 {
+    // Copy the array, since part numbers get overridden once they are read
+    let mut array = array.to_vec();
+    let part_numbers = get_part_numbers(&mut array);
+    part_numbers.iter().sum()
+}
+
+fn solve_part2(array: &[Vec<u32>]) -> u32 {
+    // Copy the array, since part numbers get overridden once they are read
+    let mut array = array.to_vec();
+    let gear_ratii = get_gear_ratii(&mut array);
+    gear_ratii.iter().sum()
+}
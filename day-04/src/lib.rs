@@ -0,0 +1,161 @@
+//! Solution to day 4 of Advent of Code 2023: Scratchcards
+use aoc_common::{Line, ParseError, RawInput, Solution};
+use std::cmp;
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_parse_line_errors() {
+        let input = RawInput::from("Card 1: 41 48 | 83 8x\nCard 2: 41 48 83 86\nCard 3 41 | 83");
+        let errors: Vec<ParseError> = input
+            .lines()
+            .map(|line| parse_line(&line).unwrap_err())
+            .collect();
+        assert_eq!((errors[0].line, errors[0].column), (1, 20));
+        assert_eq!(errors[0].token, "8x");
+        assert_eq!((errors[1].line, errors[1].column), (2, 20));
+        assert_eq!(
+            errors[1].message,
+            "expected '|' between the sets of numbers"
+        );
+        assert_eq!((errors[2].line, errors[2].column), (3, 15));
+        let input = RawInput::from("x: 41 | 83\nCard 3: 41 | 83\nCard three: 41 | 83");
+        let errors: Vec<ParseError> = input
+            .lines()
+            .map(|line| parse_line(&line).unwrap_err())
+            .collect();
+        assert_eq!((errors[0].line, errors[0].column), (1, 1));
+        assert_eq!(errors[0].message, "expected 'Card <number>'");
+        assert_eq!((errors[1].line, errors[1].column), (2, 6));
+        assert_eq!(errors[1].message, "expected card 2");
+        assert_eq!(errors[2].token, "three");
+    }
+
+    #[test]
+    fn test_truncated_table() {
+        let input = RawInput::from("Card 1: 41 48 | 83 48\nCard 2: 41 48 | 41 48");
+        let cards = Day04::parse(&input).unwrap();
+        assert_eq!(Day04::part1(&cards), Some(3));
+        assert_eq!(Day04::part2(&cards), Some(3));
+    }
+
+    #[test]
+    fn test_part1() {
+        let fname = String::from("data/test_input");
+        let input = Day04::parse_file(&fname).unwrap();
+        let result = Day04::part1(&input);
+        assert_eq!(result, Some(13));
+    }
+
+    #[test]
+    fn test_part2() {
+        let fname = String::from("data/test_input");
+        let input = Day04::parse_file(&fname).unwrap();
+        let result = Day04::part2(&input);
+        assert_eq!(result, Some(30));
+    }
+}
+
+/// Scratchcard with the numbers we have and the winning ones
+#[derive(Debug)]
+pub struct Scratchcard {
+    pub numbers: Vec<u32>,
+    pub winning: Vec<u32>,
+}
+
+impl Scratchcard {
+    /// Return how many of our numbers are winning numbers
+    pub fn matching_numbers(&self) -> u32 {
+        let mut matching_numbers = 0;
+        for number in self.numbers.iter() {
+            if self.winning.contains(number) {
+                matching_numbers += 1
+            }
+        }
+        matching_numbers
+    }
+
+    /// Return the points the card is worth
+    pub fn points(&self) -> u32 {
+        let matching_numbers = self.matching_numbers();
+        if matching_numbers < 2 {
+            return matching_numbers;
+        }
+        2_u32.pow(matching_numbers - 1)
+    }
+}
+
+/// Parse a scratchcard out of a line like `Card 1: 41 48 | 83 86 6`
+pub fn parse_line(line: &Line) -> Result<Scratchcard, ParseError> {
+    let (header, sets) = match line.text.split_once(":") {
+        Some(parts) => parts,
+        None => return Err(line.error_at_end("expected ':' after the card number")),
+    };
+    let number = match header.strip_prefix("Card") {
+        Some(number) => number.trim(),
+        None => return Err(line.error(header, "expected 'Card <number>'")),
+    };
+    // Cards are listed in order, one per line
+    match number.parse::<usize>() {
+        Ok(number) if number == line.number => (),
+        Ok(_) => return Err(line.error(number, &format!("expected card {}", line.number))),
+        Err(_) => return Err(line.error(number, "invalid card number")),
+    }
+    let (winning, numbers) = match sets.split_once("|") {
+        Some(sets) => sets,
+        None => return Err(line.error_at_end("expected '|' between the sets of numbers")),
+    };
+    let winning = parse_numbers(line, winning)?;
+    let numbers = parse_numbers(line, numbers)?;
+    Ok(Scratchcard { numbers, winning })
+}
+
+fn parse_numbers(line: &Line, numbers: &str) -> Result<Vec<u32>, ParseError> {
+    numbers
+        .split(" ")
+        .filter(|x| !x.is_empty())
+        .map(|x| x.parse().map_err(|_| line.error(x, "invalid number")))
+        .collect()
+}
+
+/// Solution to the puzzle of day 4
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Scratchcard>;
+    type Answer = u32;
+
+    fn parse(input: &RawInput) -> Result<Self::Input, ParseError> {
+        input.lines().map(|line| parse_line(&line)).collect()
+    }
+
+    fn part1(cards: &Self::Input) -> Option<u32> {
+        Some(solve_part1(cards))
+    }
+
+    fn part2(cards: &Self::Input) -> Option<u32> {
+        Some(solve_part2(cards))
+    }
+}
+
+fn solve_part1(cards: &[Scratchcard]) -> u32 {
+    cards.iter().map(|x| x.points()).sum()
+}
+
+fn solve_part2(cards: &[Scratchcard]) -> u32 {
+    let mut copies: Vec<u32> = vec![0; cards.len()];
+    for (i, card) in cards.iter().enumerate() {
+        let matching_numbers = card.matching_numbers() as usize;
+        // Cards never make us copy cards past the end of the table
+        let last = cmp::min(i + matching_numbers, cards.len() - 1);
+        for j in i + 1..last + 1 {
+            copies[j] += 1 + copies[i]
+        }
+    }
+    // Count total number of cards (including original and copies)
+    let n_copies: u32 = copies.iter().sum();
+    let n_originals = cards.len() as u32;
+    n_originals + n_copies
+}
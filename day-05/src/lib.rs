@@ -0,0 +1,883 @@
+//! Solution to day 5 of Advent of Code 2023: If You Give A Seed A Fertilizer
+use aoc_common::{Line, ParseError, RawInput, Solution};
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_map() {
+        let mut map = Map::new("seed", "soil");
+        map.push(50, 98, 2);
+        map.push(52, 50, 48);
+        assert_eq!(map.get(&0), 0);
+        assert_eq!(map.get(&1), 1);
+        assert_eq!(map.get(&48), 48);
+        assert_eq!(map.get(&49), 49);
+        assert_eq!(map.get(&50), 52);
+        assert_eq!(map.get(&51), 53);
+        assert_eq!(map.get(&96), 98);
+        assert_eq!(map.get(&97), 99);
+        assert_eq!(map.get(&98), 50);
+        assert_eq!(map.get(&99), 51);
+    }
+
+    #[test]
+    fn test_map_top_of_range() {
+        let max = u64::MAX;
+        let mut map = Map::new("seed", "soil");
+        map.push(max - 9, 0, 10);
+        map.push(0, max - 9, 10);
+        assert_eq!(map.get(&0), max - 9);
+        assert_eq!(map.get(&9), max);
+        assert_eq!(map.get(&10), 10);
+        assert_eq!(map.get(&(max - 10)), max - 10);
+        assert_eq!(map.get(&(max - 9)), 0);
+        assert_eq!(map.get(&max), 9);
+        assert_eq!(map.get_inverse(&max), vec![9]);
+        assert_eq!(map.get_inverse(&0), vec![max - 9]);
+        let mut result = map.get_intervals(&[Interval::new(max - 14, 15)]);
+        result.sort_by_key(|i| i.start);
+        assert_eq!(
+            result,
+            vec![Interval::new(0, 10), Interval::new(max - 14, 5)]
+        );
+        let piecewise = PiecewiseMap::from_map(&map);
+        assert_eq!(piecewise.pieces().len(), 3);
+        assert_eq!(piecewise.get(&max), 9);
+        assert_eq!(piecewise.get(&9), max);
+        // Swapping both ends twice leaves every value in place
+        let maps = [map, Map::new("soil", "location")];
+        let swapped = PiecewiseMap::compose(&[maps[0].clone(), maps[0].clone()]);
+        assert_eq!(
+            swapped.pieces(),
+            &[Piece {
+                start: 0,
+                offset: 0
+            }]
+        );
+        let result = get_seed_intervals(&[Interval::new(0, 3)], &maps);
+        assert_eq!(result, vec![Interval::new(max - 9, 3)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_map_push_past_the_largest_value() {
+        Map::new("seed", "soil").push(0, u64::MAX, 2);
+    }
+
+    #[test]
+    fn test_find_overlap() {
+        let mut map = Map::new("seed", "soil");
+        map.push(50, 98, 2);
+        map.push(52, 50, 48);
+        assert_eq!(map.find_overlap(), None);
+        map.push(0, 0, 0);
+        map.push(0, 99, 1);
+        assert_eq!(map.find_overlap(), Some((0, 3)));
+        let mut map = Map::new("seed", "soil");
+        map.push(0, 10, 100);
+        map.push(0, 0, 5);
+        map.push(0, 50, 5);
+        assert_eq!(map.find_overlap(), Some((0, 2)));
+    }
+
+    #[test]
+    fn test_map_intervals() {
+        let mut map = Map::new("seed", "soil");
+        map.push(50, 98, 2);
+        map.push(52, 50, 48);
+        let result = map.get_intervals(&[Interval::new(79, 14), Interval::new(55, 13)]);
+        assert_eq!(result, vec![Interval::new(81, 14), Interval::new(57, 13)]);
+        // Split across both rules and the values left unmapped
+        let mut result = map.get_intervals(&[Interval::new(45, 60)]);
+        result.sort_by_key(|i| i.start);
+        assert_eq!(
+            result,
+            vec![
+                Interval::new(45, 5),
+                Interval::new(50, 2),
+                Interval::new(52, 48),
+                Interval::new(100, 5),
+            ]
+        );
+        assert_eq!(map.get_intervals(&[Interval::new(0, 0)]), vec![]);
+    }
+
+    #[test]
+    fn test_piecewise_map() {
+        let mut map = Map::new("seed", "soil");
+        map.push(50, 98, 2);
+        map.push(52, 50, 48);
+        let piecewise = PiecewiseMap::from_map(&map);
+        let pieces: Vec<(u64, i128)> = piecewise
+            .pieces()
+            .iter()
+            .map(|p| (p.start, p.offset))
+            .collect();
+        assert_eq!(pieces, vec![(0, 0), (50, 2), (98, -48), (100, 0)]);
+        for value in (0..200).chain([u64::MAX]) {
+            assert_eq!(piecewise.get(&value), map.get(&value), "value: {}", value);
+        }
+    }
+
+    #[test]
+    fn test_compose() {
+        let fname = String::from("data/test_input");
+        let almanac = Day05::parse_file(&fname).unwrap();
+        let composed = PiecewiseMap::compose(&almanac.maps);
+        for seed in (0..200).chain([u64::MAX - 1, u64::MAX]) {
+            let expected = get_location(&seed, &almanac.maps);
+            assert_eq!(composed.get(&seed), expected, "seed: {}", seed);
+        }
+        // Consecutive pieces are merged when they have the same offset
+        let pieces = composed.pieces();
+        assert!(pieces.windows(2).all(|w| w[0].start < w[1].start));
+        assert!(pieces.windows(2).all(|w| w[0].offset != w[1].offset));
+        assert_eq!(
+            PiecewiseMap::compose(&[]).pieces(),
+            &[Piece {
+                start: 0,
+                offset: 0
+            }]
+        );
+    }
+
+    #[test]
+    fn test_map_inverse() {
+        let mut map = Map::new("seed", "soil");
+        map.push(50, 98, 2);
+        map.push(52, 50, 48);
+        assert_eq!(map.get_inverse(&10), vec![10]);
+        assert_eq!(map.get_inverse(&50), vec![98]);
+        assert_eq!(map.get_inverse(&51), vec![99]);
+        assert_eq!(map.get_inverse(&99), vec![97]);
+        assert_eq!(map.get_inverse(&100), vec![100]);
+        let mut map = Map::new("seed", "soil");
+        map.push(0, 10, 5);
+        assert_eq!(map.get_inverse(&2), vec![2, 12]);
+        assert_eq!(map.get_inverse(&12), vec![]);
+    }
+
+    #[test]
+    fn test_map_inverse_intervals() {
+        let mut map = Map::new("seed", "soil");
+        map.push(50, 98, 2);
+        map.push(52, 50, 48);
+        let result = map.get_inverse_intervals(&[Interval::new(50, 2)]);
+        assert_eq!(result, vec![Interval::new(98, 2)]);
+        let result = map.get_inverse_intervals(&[Interval::new(45, 15)]);
+        assert_eq!(result, vec![Interval::new(45, 13), Interval::new(98, 2)]);
+        let result = map.get_inverse_intervals(&[Interval::new(u64::MAX, 1)]);
+        assert_eq!(result, vec![Interval::new(u64::MAX, 1)]);
+    }
+
+    #[test]
+    fn test_seeds_of_locations() {
+        let fname = String::from("data/test_input");
+        let almanac = Day05::parse_file(&fname).unwrap();
+        let maps = &almanac.maps;
+        assert!(get_seeds(&82, maps).contains(&79));
+        assert!(get_seeds(&35, maps).contains(&13));
+        for location in 0..200 {
+            for seed in get_seeds(&location, maps) {
+                assert_eq!(get_location(&seed, maps), location);
+            }
+        }
+        let seeds = get_seed_intervals(&[Interval::new(46, 1)], maps);
+        assert!(seeds.iter().any(|seeds| seeds.contains(&82)));
+        assert_eq!(solve_part2_inverse_search(&almanac), Some(46));
+    }
+
+    #[test]
+    fn test_seed_ranges() {
+        let fname = String::from("data/test_input");
+        let almanac = Day05::parse_file(&fname).unwrap();
+        let ranges = almanac.seed_ranges().unwrap();
+        assert_eq!(ranges, vec![Interval::new(79, 14), Interval::new(55, 13)]);
+        let input = RawInput::from("seeds: 79 14 55\n\nseed-to-location map:\n50 98 2\n");
+        let almanac = parse_input(&input).unwrap();
+        assert_eq!(almanac.seed_ranges(), None);
+        let input = RawInput::from("seeds: 18446744073709551615 2\n\nseed-to-location map:\n");
+        let almanac = parse_input(&input).unwrap();
+        assert_eq!(almanac.seed_ranges(), None);
+    }
+
+    #[test]
+    fn test_locations() {
+        let fname = String::from("data/test_input");
+        let almanac = Day05::parse_file(&fname).unwrap();
+        let (seeds, maps) = (&almanac.seeds, &almanac.maps);
+        assert_eq!(get_location(&seeds[0], maps), 82);
+        assert_eq!(get_location(&seeds[1], maps), 43);
+        assert_eq!(get_location(&seeds[2], maps), 86);
+        assert_eq!(get_location(&seeds[3], maps), 35);
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        let input = RawInput::from("seeds: 79 14 55 13\n\nseed-to-soil map:\n50 98\n");
+        let error = parse_input(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+        assert_eq!(error.token, "50 98");
+        let input = RawInput::from("seeds: 79 14 5S 13\n\nseed-to-soil map:\n50 98 2\n");
+        let error = parse_input(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 14));
+        assert_eq!(error.token, "5S");
+        let input = RawInput::from("seeds:\n\nseed-to-location map:\n50 98 2\n");
+        let error = parse_input(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 7));
+        assert_eq!(error.message, "expected at least one seed");
+        let input = RawInput::from("seeds: 79\n\n50 98 2\n");
+        let error = parse_input(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.message, "expected map title");
+        let input = RawInput::from("\n");
+        let error = parse_input(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        let input = RawInput::from("seeds: 1\n\nseed-to-location map:\n50 98 2\n52 50 49\n");
+        let error = parse_input(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (5, 1));
+        assert_eq!(error.message, "source range overlaps the one on line 4");
+        let input = RawInput::from("seeds: 1\n\nseed-to-location map:\n0 18446744073709551615 2\n");
+        let error = parse_input(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+        assert_eq!(error.message, "range goes past the largest value");
+        let input = RawInput::from("seeds: 1\n\nseed-to-location map:\n0 18446744073709551615 1\n");
+        let almanac = parse_input(&input).unwrap();
+        assert_eq!(get_location(&u64::MAX, &almanac.maps), 0);
+    }
+
+    #[test]
+    fn test_parse_titles() {
+        let fname = String::from("data/test_input");
+        let almanac = Day05::parse_file(&fname).unwrap();
+        let categories = [
+            "seed",
+            "soil",
+            "fertilizer",
+            "water",
+            "light",
+            "temperature",
+            "humidity",
+            "location",
+        ];
+        assert_eq!(almanac.categories(), categories);
+        assert_eq!(almanac.maps[3].from, "water");
+        assert_eq!(almanac.maps[3].to, "light");
+        assert_eq!(almanac.maps_between("soil", "humidity").unwrap().len(), 5);
+        assert_eq!(almanac.convert(&79, "seed", "location"), Some(82));
+        assert_eq!(almanac.convert(&81, "soil", "light"), Some(74));
+        assert_eq!(almanac.convert(&74, "light", "light"), Some(74));
+        assert_eq!(almanac.convert(&74, "location", "seed"), None);
+        assert_eq!(almanac.convert(&74, "seed", "planet"), None);
+    }
+
+    #[test]
+    fn test_chain_errors() {
+        let seeds = "seeds: 79 14\n\n";
+        let cases = [
+            (
+                "seed-to-soil map:\n\nwater-to-location map:\n",
+                (5, 1),
+                "missing map from 'soil'",
+            ),
+            (
+                "soil-to-location map:\n\nseed-to-soil map:\n",
+                (3, 1),
+                "map out of order, expected the map from 'seed'",
+            ),
+            (
+                "seed-to-soil map:\n\nsoil-to-seed map:\n",
+                (5, 9),
+                "category already converted to",
+            ),
+            (
+                "seed-to-soil map:\n",
+                (4, 1),
+                "missing map from 'soil' to 'location'",
+            ),
+            ("seed-soil map:\n", (3, 1), "expected map title"),
+        ];
+        for (maps, position, message) in cases {
+            let input = RawInput::from(format!("{}{}", seeds, maps).as_str());
+            let error = parse_input(&input).unwrap_err();
+            assert_eq!((error.line, error.column), position, "maps: {}", maps);
+            assert_eq!(error.message, message);
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        let fname = String::from("data/test_input");
+        let input = Day05::parse_file(&fname).unwrap();
+        let result = Day05::part1(&input);
+        assert_eq!(result, Some(35));
+    }
+
+    #[test]
+    fn test_part2() {
+        let fname = String::from("data/test_input");
+        let input = Day05::parse_file(&fname).unwrap();
+        let result = Day05::part2(&input);
+        assert_eq!(result, Some(46));
+    }
+}
+
+/// Rule that maps a range of source values to a range of destination values
+#[derive(Debug, Clone)]
+pub struct Rule {
+    pub dest: u64,
+    pub source: u64,
+    pub len: u64,
+}
+
+/// Range of consecutive values, given by its first value and its length
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interval {
+    pub start: u64,
+    pub len: u64,
+}
+
+impl Interval {
+    /// # Panics
+    ///
+    /// Panics if the interval goes past the largest `u64` value.
+    pub fn new(start: u64, len: u64) -> Self {
+        assert!(fits(start, len), "interval goes past the largest value");
+        Self { start, len }
+    }
+
+    /// First value after the interval, which may not fit in a `u64`
+    fn end(&self) -> u128 {
+        self.start as u128 + self.len as u128
+    }
+
+    pub fn contains(&self, value: &u64) -> bool {
+        value
+            .checked_sub(self.start)
+            .is_some_and(|delta| delta < self.len)
+    }
+}
+
+/// Check that a range of values doesn't go past the largest `u64` value
+fn fits(start: u64, len: u64) -> bool {
+    len == 0 || start.checked_add(len - 1).is_some()
+}
+
+/// Map from one category to another (e.g. from seeds to soils)
+///
+/// Values that aren't covered by any rule are mapped to themselves.
+#[derive(Debug, Clone, Default)]
+pub struct Map {
+    /// Category of the values the map is applied to
+    pub from: String,
+    /// Category of the values the map returns
+    pub to: String,
+    /// Rules of the map, which only [`Map::push`] adds so that their ranges
+    /// are known to fit
+    rules: Vec<Rule>,
+}
+
+/// Seeds that need to be planted and maps to find their locations
+#[derive(Debug)]
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    /// Maps in the order they need to be applied, chained from seeds to
+    /// locations
+    pub maps: Vec<Map>,
+}
+
+impl Almanac {
+    /// Interpret the seeds as pairs of start and length of ranges of seeds
+    ///
+    /// Returns `None` if there's an odd number of seeds, or if a range goes
+    /// past the largest `u64` value.
+    pub fn seed_ranges(&self) -> Option<Vec<Interval>> {
+        if !self.seeds.len().is_multiple_of(2) {
+            return None;
+        }
+        self.seeds
+            .chunks(2)
+            .map(|pair| fits(pair[0], pair[1]).then(|| Interval::new(pair[0], pair[1])))
+            .collect()
+    }
+
+    /// Categories along the chain of maps, from seeds to locations
+    pub fn categories(&self) -> Vec<&str> {
+        let mut categories = vec!["seed"];
+        categories.extend(self.maps.iter().map(|map| map.to.as_str()));
+        categories
+    }
+
+    /// Maps that convert values from one category to another, in the order
+    /// they need to be applied
+    ///
+    /// Returns `None` if a category is unknown or comes after the other one
+    /// in the chain.
+    pub fn maps_between(&self, from: &str, to: &str) -> Option<&[Map]> {
+        let categories = self.categories();
+        let start = categories.iter().position(|c| *c == from)?;
+        let end = categories.iter().position(|c| *c == to)?;
+        self.maps.get(start..end)
+    }
+
+    /// Convert a value from one category to another
+    pub fn convert(&self, value: &u64, from: &str, to: &str) -> Option<u64> {
+        Some(get_location(value, self.maps_between(from, to)?))
+    }
+}
+
+impl Map {
+    pub fn new(from: &str, to: &str) -> Self {
+        Self {
+            from: String::from(from),
+            to: String::from(to),
+            rules: Vec::new(),
+        }
+    }
+
+    /// Add a rule to the map
+    ///
+    /// # Panics
+    ///
+    /// Panics if the source or destination range goes past the largest
+    /// `u64` value.
+    pub fn push(&mut self, dest: u64, source: u64, len: u64) {
+        assert!(
+            fits(source, len) && fits(dest, len),
+            "rule goes past the largest value"
+        );
+        self.rules.push(Rule { dest, source, len })
+    }
+
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    /// Map a value through the rules of the map
+    pub fn get(&self, value: &u64) -> u64 {
+        for rule in self.rules.iter() {
+            if let Some(delta) = value.checked_sub(rule.source) {
+                if delta < rule.len {
+                    // Can't overflow, since push checks that the destination
+                    // range fits
+                    return rule.dest + delta;
+                }
+            }
+        }
+        *value
+    }
+
+    /// Find two rules whose source ranges overlap
+    ///
+    /// Returns the indices of the rules in increasing order, or `None` if
+    /// every value is covered by at most one rule.
+    pub fn find_overlap(&self) -> Option<(usize, usize)> {
+        let mut order: Vec<usize> = (0..self.rules.len())
+            .filter(|i| self.rules[*i].len > 0)
+            .collect();
+        order.sort_by_key(|i| self.rules[*i].source);
+        // If a rule overlaps any other one, it overlaps the next one by source
+        for pair in order.windows(2) {
+            let (first, second) = (&self.rules[pair[0]], &self.rules[pair[1]]);
+            if second.source - first.source < first.len {
+                return Some((pair[0].min(pair[1]), pair[0].max(pair[1])));
+            }
+        }
+        None
+    }
+
+    /// Map intervals of values through the rules of the map
+    ///
+    /// Intervals that overlap several rules are split, so every piece is
+    /// shifted by the rule it falls in. Pieces not covered by any rule are
+    /// kept as they are. The order of the resulting intervals is unspecified.
+    pub fn get_intervals(&self, intervals: &[Interval]) -> Vec<Interval> {
+        let mut mapped = vec![];
+        // Pieces that haven't matched any of the rules seen so far
+        let mut pending: Vec<Interval> = intervals.iter().filter(|i| i.len > 0).copied().collect();
+        for rule in self.rules.iter() {
+            let rule_start = rule.source as u128;
+            let rule_end = rule_start + rule.len as u128;
+            let mut unmatched = vec![];
+            for interval in pending {
+                let start = rule_start.max(interval.start as u128);
+                let end = rule_end.min(interval.end());
+                if start >= end {
+                    unmatched.push(interval);
+                    continue;
+                }
+                let dest = start - rule_start + rule.dest as u128;
+                mapped.push(Interval::new(dest as u64, (end - start) as u64));
+                if (interval.start as u128) < start {
+                    let len = start - interval.start as u128;
+                    unmatched.push(Interval::new(interval.start, len as u64));
+                }
+                if end < interval.end() {
+                    unmatched.push(Interval::new(end as u64, (interval.end() - end) as u64));
+                }
+            }
+            pending = unmatched;
+        }
+        mapped.extend(pending);
+        mapped
+    }
+
+    /// Return every value that the map sends to the given one, sorted
+    pub fn get_inverse(&self, value: &u64) -> Vec<u64> {
+        // Either the value is left as it is, or it comes from one of the rules
+        let mut candidates = vec![*value];
+        for rule in self.rules.iter() {
+            if let Some(delta) = value.checked_sub(rule.dest) {
+                if delta < rule.len {
+                    candidates.push(rule.source + delta);
+                }
+            }
+        }
+        // Discard candidates that are mapped by an earlier rule instead
+        candidates.retain(|c| self.get(c) == *value);
+        candidates.sort();
+        candidates.dedup();
+        candidates
+    }
+
+    /// Return the intervals of values that the map sends to the given ones
+    pub fn get_inverse_intervals(&self, intervals: &[Interval]) -> Vec<Interval> {
+        PiecewiseMap::from_map(self).get_inverse_intervals(intervals)
+    }
+}
+
+/// Piece of a piecewise-linear map
+///
+/// Values from `start` up to the start of the next piece are shifted by
+/// `offset`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Piece {
+    pub start: u64,
+    pub offset: i128,
+}
+
+/// Map from one category to another as a piecewise-linear function
+///
+/// Pieces are sorted by their start and cover every `u64` value, with values
+/// not covered by any rule in pieces of offset 0. This allows to look up a
+/// value with a binary search and to compose several maps into one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PiecewiseMap {
+    pieces: Vec<Piece>,
+}
+
+impl PiecewiseMap {
+    /// Build the piecewise-linear function equivalent to a map
+    ///
+    /// As in [`Map::get`], the first rule that contains a value applies when
+    /// rules overlap.
+    pub fn from_map(map: &Map) -> Self {
+        let mut breakpoints: Vec<u64> = vec![0];
+        for rule in map.rules.iter() {
+            breakpoints.push(rule.source);
+            // The end of a rule is a breakpoint, unless it covers the last value
+            if let Some(end) = rule.source.checked_add(rule.len) {
+                breakpoints.push(end);
+            }
+        }
+        breakpoints.sort();
+        breakpoints.dedup();
+        let mut result = Self { pieces: vec![] };
+        for start in breakpoints {
+            // The same rule applies to every value up to the next breakpoint
+            let offset = map.get(&start) as i128 - start as i128;
+            result.push(start, offset);
+        }
+        result
+    }
+
+    /// Compose maps in the order they need to be applied
+    pub fn compose(maps: &[Map]) -> Self {
+        let identity = Self {
+            pieces: vec![Piece {
+                start: 0,
+                offset: 0,
+            }],
+        };
+        maps.iter()
+            .fold(identity, |result, map| result.then(&Self::from_map(map)))
+    }
+
+    /// Pieces of the function, sorted by their start
+    pub fn pieces(&self) -> &[Piece] {
+        &self.pieces
+    }
+
+    /// Map a value through the function
+    pub fn get(&self, value: &u64) -> u64 {
+        let piece = &self.pieces[self.index(*value as i128)];
+        (*value as i128 + piece.offset) as u64
+    }
+
+    /// Return the function that applies this one and then `next`
+    pub fn then(&self, next: &Self) -> Self {
+        let mut result = Self { pieces: vec![] };
+        for (i, piece) in self.pieces.iter().enumerate() {
+            // Split the image of the piece at the breakpoints of `next`
+            let start = piece.start as i128;
+            let end = self.end(i);
+            let mut j = next.index(start + piece.offset);
+            while j < next.pieces.len() && (next.pieces[j].start as i128) < end + piece.offset {
+                let split = (next.pieces[j].start as i128 - piece.offset).max(start);
+                result.push(split as u64, piece.offset + next.pieces[j].offset);
+                j += 1;
+            }
+        }
+        result
+    }
+
+    /// Return the intervals of values that the function sends to the given
+    /// ones, sorted and with contiguous intervals merged
+    pub fn get_inverse_intervals(&self, intervals: &[Interval]) -> Vec<Interval> {
+        let mut result: Vec<Interval> = vec![];
+        for (i, piece) in self.pieces.iter().enumerate() {
+            let image_start = piece.start as i128 + piece.offset;
+            let image_end = self.end(i) + piece.offset;
+            for interval in intervals {
+                let start = image_start.max(interval.start as i128);
+                let end = image_end.min(interval.end() as i128);
+                if start < end {
+                    let source = (start - piece.offset) as u64;
+                    result.push(Interval::new(source, (end - start) as u64));
+                }
+            }
+        }
+        result.sort_by_key(|i| i.start);
+        let mut merged: Vec<Interval> = vec![];
+        for interval in result {
+            let end = merged.last().map(|last| last.end().max(interval.end()));
+            match merged.last_mut() {
+                // Merge unless the length wouldn't fit in a `u64`
+                Some(last)
+                    if last.end() >= interval.start as u128
+                        && end.unwrap() - (last.start as u128) <= u64::MAX as u128 =>
+                {
+                    last.len = (end.unwrap() - last.start as u128) as u64;
+                }
+                _ => merged.push(interval),
+            }
+        }
+        merged
+    }
+
+    /// Index of the piece that contains a value
+    fn index(&self, value: i128) -> usize {
+        let n_before = self.pieces.partition_point(|p| p.start as i128 <= value);
+        n_before.max(1) - 1
+    }
+
+    /// First value after the `i`-th piece
+    fn end(&self, i: usize) -> i128 {
+        match self.pieces.get(i + 1) {
+            Some(piece) => piece.start as i128,
+            None => u64::MAX as i128 + 1,
+        }
+    }
+
+    /// Add a piece at the end, merging it with the last one if they have the
+    /// same offset
+    fn push(&mut self, start: u64, offset: i128) {
+        if self.pieces.last().is_some_and(|p| p.offset == offset) {
+            return;
+        }
+        self.pieces.push(Piece { start, offset });
+    }
+}
+
+/// Parse the almanac out of the puzzle input
+pub fn parse_input(input: &RawInput) -> Result<Almanac, ParseError> {
+    let mut blocks = input.blocks();
+    // Read seeds
+    let seeds_line = match blocks.next() {
+        Some(block) => block[0],
+        None => return Err(input.error_at_end("expected the list of seeds")),
+    };
+    let seeds = match seeds_line.text.strip_prefix("seeds:") {
+        Some(seeds) => parse_numbers(&seeds_line, seeds)?,
+        None => return Err(seeds_line.error(seeds_line.text, "expected 'seeds:'")),
+    };
+    if seeds.is_empty() {
+        return Err(seeds_line.error_at_end("expected at least one seed"));
+    }
+    // Read maps
+    let mut maps: Vec<Map> = Vec::new();
+    let mut titles = Vec::new();
+    for block in blocks {
+        let title = block[0];
+        let (from, to) = parse_title(&title)?;
+        titles.push((title, from, to));
+        let mut map = Map::new(from, to);
+        for line in block.iter().skip(1) {
+            let map_values = parse_numbers(line, line.text)?;
+            if map_values.len() != 3 {
+                return Err(line.error(
+                    line.text,
+                    "expected three numbers: destination, source and length",
+                ));
+            }
+            let (dest, source, len) = (map_values[0], map_values[1], map_values[2]);
+            if !fits(source, len) || !fits(dest, len) {
+                return Err(line.error(line.text, "range goes past the largest value"));
+            }
+            map.push(dest, source, len);
+        }
+        if let Some((first, second)) = map.find_overlap() {
+            // Rules start on the line after the title
+            let (first, second) = (block[first + 1], block[second + 1]);
+            let message = format!("source range overlaps the one on line {}", first.number);
+            return Err(second.error(second.text, &message));
+        }
+        maps.push(map);
+    }
+    check_chain(input, &titles)?;
+    Ok(Almanac { seeds, maps })
+}
+
+/// Parse the categories out of a map title like `seed-to-soil map:`
+fn parse_title<'a>(title: &Line<'a>) -> Result<(&'a str, &'a str), ParseError> {
+    let categories = match title.text.strip_suffix(" map:") {
+        Some(categories) => categories.split_once("-to-"),
+        None => None,
+    };
+    match categories {
+        Some((from, to)) if !from.is_empty() && !to.is_empty() => Ok((from, to)),
+        _ => Err(title.error(title.text, "expected map title")),
+    }
+}
+
+/// Check that the maps form a chain from seeds to locations
+///
+/// # Arguments
+///
+/// * `input` - puzzle input, to report maps missing at its end
+/// * `titles` - title line of every map, along with its source and
+///   destination categories
+fn check_chain(input: &RawInput, titles: &[(Line, &str, &str)]) -> Result<(), ParseError> {
+    let mut category = "seed";
+    let mut seen = vec![category];
+    for (title, from, to) in titles.iter() {
+        if *from != category {
+            let message = if titles.iter().any(|(_, from, _)| *from == category) {
+                format!("map out of order, expected the map from '{}'", category)
+            } else {
+                format!("missing map from '{}'", category)
+            };
+            return Err(title.error(from, &message));
+        }
+        if seen.contains(to) {
+            return Err(title.error(to, "category already converted to"));
+        }
+        seen.push(to);
+        category = to;
+    }
+    if category != "location" {
+        let message = format!("missing map from '{}' to 'location'", category);
+        return Err(input.error_at_end(&message));
+    }
+    Ok(())
+}
+
+fn parse_numbers(line: &Line, numbers: &str) -> Result<Vec<u64>, ParseError> {
+    numbers
+        .split(" ")
+        .filter(|x| !x.is_empty())
+        .map(|x| x.parse().map_err(|_| line.error(x, "invalid number")))
+        .collect()
+}
+
+/// Return the location of a seed by applying every map in order
+pub fn get_location(seed: &u64, maps: &[Map]) -> u64 {
+    let mut value = *seed;
+    for map in maps.iter() {
+        value = map.get(&value);
+    }
+    value
+}
+
+/// Return the intervals of locations of intervals of seeds
+pub fn get_location_intervals(seeds: &[Interval], maps: &[Map]) -> Vec<Interval> {
+    let mut intervals = seeds.to_vec();
+    for map in maps.iter() {
+        intervals = map.get_intervals(&intervals);
+    }
+    intervals
+}
+
+/// Return every seed that ends up in the given location, sorted
+pub fn get_seeds(location: &u64, maps: &[Map]) -> Vec<u64> {
+    let mut values = vec![*location];
+    for map in maps.iter().rev() {
+        values = values.iter().flat_map(|v| map.get_inverse(v)).collect();
+    }
+    values.sort();
+    values.dedup();
+    values
+}
+
+/// Return the intervals of seeds that end up in the given intervals of
+/// locations
+pub fn get_seed_intervals(locations: &[Interval], maps: &[Map]) -> Vec<Interval> {
+    let mut intervals = locations.to_vec();
+    for map in maps.iter().rev() {
+        intervals = map.get_inverse_intervals(&intervals);
+    }
+    intervals
+}
+
+/// Solution to the puzzle of day 5
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Almanac;
+    type Answer = u64;
+
+    fn parse(input: &RawInput) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(almanac: &Self::Input) -> Option<u64> {
+        Some(solve_part1(almanac))
+    }
+
+    fn part2(almanac: &Self::Input) -> Option<u64> {
+        solve_part2(almanac)
+    }
+}
+
+fn solve_part1(almanac: &Almanac) -> u64 {
+    let maps = &almanac.maps;
+    let min_location = almanac
+        .seeds
+        .iter()
+        .map(|s| get_location(s, maps))
+        .min()
+        .unwrap();
+    min_location
+}
+
+fn solve_part2(almanac: &Almanac) -> Option<u64> {
+    let seeds = almanac.seed_ranges()?;
+    let locations = get_location_intervals(&seeds, &almanac.maps);
+    locations.iter().map(|i| i.start).min()
+}
+
+/// Solve part 2 by searching the locations upwards from zero until one of
+/// them is reached from a seed in the seed ranges
+///
+/// It's much slower than mapping the seed ranges, but it doesn't share any
+/// code with it, so it can be used to verify its answer.
+pub fn solve_part2_inverse_search(almanac: &Almanac) -> Option<u64> {
+    let ranges = almanac.seed_ranges()?;
+    (0..=u64::MAX).find(|location| {
+        get_seeds(location, &almanac.maps)
+            .iter()
+            .any(|seed| ranges.iter().any(|range| range.contains(seed)))
+    })
+}
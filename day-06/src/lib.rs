@@ -0,0 +1,243 @@
+//! Solution to day 6 of Advent of Code 2023: Wait For It
+use aoc_common::{Line, Lines, ParseError, RawInput, Solution};
+use std::iter::zip;
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_parse_file_errors() {
+        let input = RawInput::from("Time:      7  15   30\nDistance:  9  4O  200");
+        let error = parse_file_part1(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 15));
+        assert_eq!(error.token, "4O");
+        let error = parse_file_part2(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 12));
+        let input = RawInput::from("Time:      7  15   30\n");
+        let error = parse_file_part1(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.message, "expected 'Distance:' line");
+    }
+
+    #[test]
+    fn test_part1_brute_force() {
+        let fname = String::from("data/test_input");
+        let races = Day06::parse_file(&fname).unwrap();
+        let result = solve_part1_brute_force(&races);
+        assert_eq!(result, 288);
+    }
+
+    #[test]
+    fn test_part1_binary_search() {
+        let fname = String::from("data/test_input");
+        let races = Day06::parse_file(&fname).unwrap();
+        let result = solve_part1_binary_search(&races);
+        assert_eq!(result, 288);
+    }
+
+    #[test]
+    fn test_part2_brute_force() {
+        let fname = String::from("data/test_input");
+        let races = Day06::parse_file(&fname).unwrap();
+        let result = solve_part2_brute_force(&races);
+        assert_eq!(result, 71503);
+    }
+
+    #[test]
+    fn test_part2_binary_search() {
+        let fname = String::from("data/test_input");
+        let races = Day06::parse_file(&fname).unwrap();
+        let result = solve_part2_binary_search(&races);
+        assert_eq!(result, 71503);
+    }
+}
+
+/// Races listed in the document
+///
+/// The document can be read as a list of races (part 1), or as a single race
+/// after ignoring the spaces between the digits (part 2).
+#[derive(Debug)]
+pub struct Races {
+    /// Durations of each race
+    pub times: Vec<u64>,
+    /// Record distances of each race
+    pub distances: Vec<u64>,
+    /// Duration of the single race
+    pub time: u64,
+    /// Record distance of the single race
+    pub distance_record: u64,
+}
+
+fn parse_file_part1(input: &RawInput) -> Result<(Vec<u64>, Vec<u64>), ParseError> {
+    let mut lines = input.lines();
+    let (line, times) = next_row(input, &mut lines, "Time:")?;
+    let times = parse_numbers(&line, times)?;
+    let (line, distances) = next_row(input, &mut lines, "Distance:")?;
+    let distances = parse_numbers(&line, distances)?;
+    Ok((times, distances))
+}
+
+fn parse_file_part2(input: &RawInput) -> Result<(u64, u64), ParseError> {
+    let mut lines = input.lines();
+    let (line, time) = next_row(input, &mut lines, "Time:")?;
+    let time = parse_kerned_number(&line, time)?;
+    let (line, distance_record) = next_row(input, &mut lines, "Distance:")?;
+    let distance_record = parse_kerned_number(&line, distance_record)?;
+    Ok((time, distance_record))
+}
+
+/// Read the next line, which should start with the given header
+///
+/// Return the line and the text that follows the header.
+fn next_row<'a>(
+    input: &RawInput,
+    lines: &mut Lines<'a>,
+    header: &str,
+) -> Result<(Line<'a>, &'a str), ParseError> {
+    let line = match lines.next() {
+        Some(line) => line,
+        None => return Err(input.error_at_end(&format!("expected '{}' line", header))),
+    };
+    match line.text.strip_prefix(header) {
+        Some(values) => Ok((line, values)),
+        None => Err(line.error(line.text, &format!("expected '{}'", header))),
+    }
+}
+
+fn parse_numbers(line: &Line, numbers: &str) -> Result<Vec<u64>, ParseError> {
+    numbers
+        .split(" ")
+        .filter(|x| !x.is_empty())
+        .map(|x| x.parse().map_err(|_| line.error(x, "invalid number")))
+        .collect()
+}
+
+/// Parse the digits in the text as a single number, ignoring the spaces
+fn parse_kerned_number(line: &Line, digits: &str) -> Result<u64, ParseError> {
+    match digits.replace(" ", "").parse() {
+        Ok(number) => Ok(number),
+        Err(_) => Err(line.error(digits.trim(), "invalid number")),
+    }
+}
+
+/// Count the ways of beating the record of a race by trying every hold time
+///
+/// # Arguments
+///
+/// * `time` - duration of the race
+/// * `distance_record` - distance that needs to be beaten
+pub fn get_number_winning_solutions(time: &u64, distance_record: &u64) -> u64 {
+    let mut n_winning_solutions = 0;
+    let mut hold_time = time.div_ceil(2);
+    let mut distance = hold_time * (*time - hold_time);
+    while distance > *distance_record {
+        n_winning_solutions += 1;
+        hold_time += 1;
+        distance = hold_time * (*time - hold_time);
+    }
+    n_winning_solutions *= 2;
+    if (*time).is_multiple_of(2) {
+        n_winning_solutions -= 1;
+    }
+    n_winning_solutions
+}
+
+fn get_distance(hold_time: &u64, total_time: &u64) -> u64 {
+    *hold_time * (*total_time - *hold_time)
+}
+
+/// Count the ways of beating the record of a race through a binary search
+///
+/// Searches for the longest hold time that beats the record, and uses the
+/// symmetry of the distances around half the race to count the rest.
+///
+/// # Arguments
+///
+/// * `time` - duration of the race
+/// * `distance_record` - distance that needs to be beaten
+pub fn binary_search(time: &u64, distance_record: &u64) -> u64 {
+    let min_time = time.div_ceil(2);
+    let max_time = *time;
+    let mut left = max_time;
+    let mut right = min_time;
+    let mut middle: u64;
+    loop {
+        if left < right {
+            panic!("Unsuccessful")
+        }
+        middle = (left + right) / 2;
+        let distance = get_distance(&middle, time);
+        if distance < *distance_record {
+            left = middle - 1;
+        } else if distance > *distance_record {
+            right = middle + 1;
+        } else {
+            break;
+        }
+        if left + 1 == right {
+            middle = right;
+            break;
+        }
+    }
+    let mut result = 2 * (middle - min_time);
+    if time.is_multiple_of(2) {
+        result -= 1
+    };
+    result
+}
+
+/// Solution to the puzzle of day 6
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Races;
+    type Answer = u64;
+
+    fn parse(input: &RawInput) -> Result<Self::Input, ParseError> {
+        let (times, distances) = parse_file_part1(input)?;
+        let (time, distance_record) = parse_file_part2(input)?;
+        Ok(Races {
+            times,
+            distances,
+            time,
+            distance_record,
+        })
+    }
+
+    fn part1(races: &Self::Input) -> Option<u64> {
+        Some(solve_part1_binary_search(races))
+    }
+
+    fn part2(races: &Self::Input) -> Option<u64> {
+        Some(solve_part2_binary_search(races))
+    }
+}
+
+/// Solve part 1 by trying every hold time of every race
+pub fn solve_part1_brute_force(races: &Races) -> u64 {
+    let mut result = 1;
+    for (time, distance_record) in zip(&races.times, &races.distances) {
+        result *= get_number_winning_solutions(time, distance_record);
+    }
+    result
+}
+
+/// Solve part 1 through a binary search on every race
+pub fn solve_part1_binary_search(races: &Races) -> u64 {
+    let mut result = 1;
+    for (time, distance_record) in zip(&races.times, &races.distances) {
+        result *= binary_search(time, distance_record);
+    }
+    result
+}
+
+/// Solve part 2 by trying every hold time of the single race
+pub fn solve_part2_brute_force(races: &Races) -> u64 {
+    get_number_winning_solutions(&races.time, &races.distance_record)
+}
+
+/// Solve part 2 through a binary search on the single race
+pub fn solve_part2_binary_search(races: &Races) -> u64 {
+    binary_search(&races.time, &races.distance_record)
+}
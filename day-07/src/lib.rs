@@ -0,0 +1,62 @@
+//! Solution to day 7 of Advent of Code 2023: Camel Cards
+use aoc_common::{ParseError, RawInput, Solution};
+
+pub mod part1;
+pub mod part2;
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_part1() {
+        let fname = String::from("data/test_input");
+        let input = Day07::parse_file(&fname).unwrap();
+        let result = Day07::part1(&input);
+        assert_eq!(result, Some(6440));
+    }
+
+    #[test]
+    fn test_part2() {
+        let fname = String::from("data/test_input");
+        let input = Day07::parse_file(&fname).unwrap();
+        let result = Day07::part2(&input);
+        assert_eq!(result, Some(5905));
+    }
+}
+
+/// Hands listed in the input
+///
+/// The `J` cards are jacks in part 1 but jokers in part 2, so the hands are
+/// parsed once for each set of rules.
+#[derive(Debug)]
+pub struct Hands {
+    pub jacks: Vec<part1::Hand>,
+    pub jokers: Vec<part2::Hand>,
+}
+
+/// Solution to the puzzle of day 7
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Hands;
+    type Answer = u32;
+
+    fn parse(input: &RawInput) -> Result<Self::Input, ParseError> {
+        let mut jacks = vec![];
+        let mut jokers = vec![];
+        for line in input.lines() {
+            jacks.push(part1::parse_line(&line)?);
+            jokers.push(part2::parse_line(&line)?);
+        }
+        Ok(Hands { jacks, jokers })
+    }
+
+    fn part1(hands: &Self::Input) -> Option<u32> {
+        Some(part1::solve_part1(&hands.jacks))
+    }
+
+    fn part2(hands: &Self::Input) -> Option<u32> {
+        Some(part2::solve_part2(&hands.jokers))
+    }
+}
@@ -1,33 +1,13 @@
-mod part1;
-mod part2;
-pub mod utils;
+use aoc_common::{Error, Solution};
+use day_07::Day07;
 
-use crate::part1::solve_part1;
-use crate::part2::solve_part2;
-
-#[cfg(test)]
-mod tests {
-    use crate::*;
-
-    #[test]
-    fn test_part1() {
-        let fname = String::from("data/test_input");
-        let result = solve_part1(&fname);
-        assert_eq!(result, 6440);
+fn main() -> Result<(), Error> {
+    let input = Day07::parse_file("data/input")?;
+    if let Some(result) = Day07::part1(&input) {
+        println!("Solution to part 1: {}", result);
     }
-
-    #[test]
-    fn test_part2() {
-        let fname = String::from("data/test_input");
-        let result = solve_part2(&fname);
-        assert_eq!(result, 5905);
+    if let Some(result) = Day07::part2(&input) {
+        println!("Solution to part 2: {}", result);
     }
-}
-
-fn main() {
-    let fname = String::from("data/input");
-    let result = solve_part1(&fname);
-    println!("Solution to part 1: {}", result);
-    let result = solve_part2(&fname);
-    println!("Solution to part 2: {}", result);
+    Ok(())
 }
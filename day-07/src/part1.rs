@@ -1,10 +1,14 @@
-use crate::utils::read_file;
+//! Camel Cards played with the rules of part 1
+//!
+//! `J` cards are jacks, ranked between `T` and `Q`.
+use aoc_common::{Line, ParseError};
 use counter::Counter;
 use std::cmp::Ordering;
 
 #[cfg(test)]
 mod tests {
-    use crate::part1::Hand;
+    use crate::part1::{parse_line, Hand};
+    use aoc_common::RawInput;
 
     #[test]
     fn test_hands_comparison_1() {
@@ -33,22 +37,42 @@ mod tests {
         assert!(hand2 > hand1);
         assert!(hand1 < hand2);
     }
+
+    #[test]
+    fn test_parse_line_errors() {
+        let input = RawInput::from("32T3K 765\n32X3K 765\nT55J5\nKK677 2A");
+        let mut lines = input.lines();
+        let hand = parse_line(&lines.next().unwrap()).unwrap();
+        assert_eq!(hand.cards, vec![3, 2, 10, 3, 13]);
+        assert_eq!(hand.bid, 765);
+        let error = parse_line(&lines.next().unwrap()).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.token, "X");
+        let error = parse_line(&lines.next().unwrap()).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 6));
+        let error = parse_line(&lines.next().unwrap()).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 7));
+        assert_eq!(error.token, "2A");
+    }
 }
 
+/// Hand of cards along with its bid
 #[derive(Debug)]
-struct Hand {
-    cards: Vec<u8>,
-    bid: u32,
+pub struct Hand {
+    /// Values of the cards, from 2 to 14 (aces)
+    pub cards: Vec<u8>,
+    pub bid: u32,
 }
 
 impl Hand {
-    fn get_cards_frequencies(&self) -> Vec<usize> {
+    /// Return how many times each card appears, sorted in decreasing order
+    pub fn get_cards_frequencies(&self) -> Vec<usize> {
         let mut card_counts: Vec<usize> = self
             .cards
             .iter()
             .collect::<Counter<_>>()
             .values()
-            .map(|x| *x)
+            .copied()
             .collect();
         card_counts.sort();
         card_counts.reverse();
@@ -82,39 +106,47 @@ impl PartialOrd for Hand {
     }
 }
 
-fn card_to_int(card: &char) -> u8 {
+fn card_to_int(card: &char) -> Option<u8> {
     if card.is_numeric() {
-        return card.to_digit(14).unwrap() as u8;
+        card.to_digit(14).map(|x| x as u8)
     } else if *card == 'A' {
-        return 14;
+        Some(14)
     } else if *card == 'K' {
-        return 13;
+        Some(13)
     } else if *card == 'Q' {
-        return 12;
+        Some(12)
     } else if *card == 'J' {
-        return 11;
+        Some(11)
     } else if *card == 'T' {
-        return 10;
+        Some(10)
     } else {
-        panic!("card not recognized");
+        None
     }
 }
 
-fn parse_line(line: &str) -> Hand {
-    let mut parts = line.split(" ").into_iter();
-    let cards = parts
-        .next()
-        .unwrap()
-        .chars()
-        .map(|c| card_to_int(&c))
-        .collect();
-    let bid = parts.next().unwrap().parse().unwrap();
-    Hand { cards, bid }
+/// Parse a hand out of a line like `32T3K 765`
+pub fn parse_line(line: &Line) -> Result<Hand, ParseError> {
+    let (cards_str, bid_str) = match line.text.split_once(" ") {
+        Some(parts) => parts,
+        None => return Err(line.error_at_end("expected bid after the cards")),
+    };
+    let mut cards = vec![];
+    for (i, c) in cards_str.char_indices() {
+        match card_to_int(&c) {
+            Some(card) => cards.push(card),
+            None => return Err(line.error(&cards_str[i..i + c.len_utf8()], "invalid card")),
+        }
+    }
+    let bid = match bid_str.parse() {
+        Ok(bid) => bid,
+        Err(_) => return Err(line.error(bid_str, "invalid bid")),
+    };
+    Ok(Hand { cards, bid })
 }
 
-pub fn solve_part1(fname: &String) -> u32 {
-    let content = read_file(fname);
-    let mut hands: Vec<Hand> = content.lines().map(|line| parse_line(&line)).collect();
+/// Return the total winnings of the hands
+pub fn solve_part1(hands: &[Hand]) -> u32 {
+    let mut hands: Vec<&Hand> = hands.iter().collect();
     hands.sort();
     let mut result = 0;
     for (i, hand) in hands.iter().enumerate() {
@@ -1,4 +1,7 @@
-use crate::utils::read_file;
+//! Camel Cards played with the rules of part 2
+//!
+//! `J` cards are jokers: the weakest card, but they act as whatever card makes the strongest hand.
+use aoc_common::{Line, ParseError};
 use std::{cmp::Ordering, collections::HashMap};
 
 #[cfg(test)]
@@ -87,14 +90,17 @@ mod tests {
     }
 }
 
+/// Hand of cards along with its bid
 #[derive(Debug)]
-struct Hand {
-    cards: Vec<u8>,
-    bid: u32,
+pub struct Hand {
+    /// Values of the cards, from 1 (jokers) to 14 (aces)
+    pub cards: Vec<u8>,
+    pub bid: u32,
 }
 
 impl Hand {
-    fn get_cards_frequencies(&self) -> Vec<usize> {
+    /// Return how many times each card appears, sorted in decreasing order
+    pub fn get_cards_frequencies(&self) -> Vec<usize> {
         // Count cards
         let mut card_counter = count_cards(&self.cards);
         // Replace jokers to get the best hand
@@ -110,14 +116,14 @@ impl Hand {
             card_counter.remove(&1);
         };
         // Sort out amount of cards of same value
-        let mut card_counts: Vec<usize> = card_counter.values().map(|x| *x).collect();
+        let mut card_counts: Vec<usize> = card_counter.values().copied().collect();
         card_counts.sort();
         card_counts.reverse();
         card_counts
     }
 }
 
-fn count_cards(cards: &Vec<u8>) -> HashMap<u8, usize> {
+fn count_cards(cards: &[u8]) -> HashMap<u8, usize> {
     let mut card_counts = HashMap::new();
     for card in cards.iter() {
         match card_counts.get_mut(card) {
@@ -168,35 +174,43 @@ impl PartialOrd for Hand {
     }
 }
 
-fn card_to_int(card: &char) -> u8 {
+fn card_to_int(card: &char) -> Option<u8> {
     match card.is_numeric() {
-        true => card.to_digit(13).unwrap() as u8,
+        true => card.to_digit(13).map(|x| x as u8),
         false => match card {
-            'A' => 13,
-            'K' => 12,
-            'Q' => 11,
-            'T' => 10,
-            'J' => 1,
-            _ => panic!("card '{}' not recognized", card),
+            'A' => Some(13),
+            'K' => Some(12),
+            'Q' => Some(11),
+            'T' => Some(10),
+            'J' => Some(1),
+            _ => None,
         },
     }
 }
 
-fn parse_line(line: &str) -> Hand {
-    let mut parts = line.split(" ").into_iter();
-    let cards = parts
-        .next()
-        .unwrap()
-        .chars()
-        .map(|c| card_to_int(&c))
-        .collect();
-    let bid = parts.next().unwrap().parse().unwrap();
-    Hand { cards, bid }
+/// Parse a hand out of a line like `32T3K 765`
+pub fn parse_line(line: &Line) -> Result<Hand, ParseError> {
+    let (cards_str, bid_str) = match line.text.split_once(" ") {
+        Some(parts) => parts,
+        None => return Err(line.error_at_end("expected bid after the cards")),
+    };
+    let mut cards = vec![];
+    for (i, c) in cards_str.char_indices() {
+        match card_to_int(&c) {
+            Some(card) => cards.push(card),
+            None => return Err(line.error(&cards_str[i..i + c.len_utf8()], "invalid card")),
+        }
+    }
+    let bid = match bid_str.parse() {
+        Ok(bid) => bid,
+        Err(_) => return Err(line.error(bid_str, "invalid bid")),
+    };
+    Ok(Hand { cards, bid })
 }
 
-pub fn solve_part2(fname: &String) -> u32 {
-    let content = read_file(fname);
-    let mut hands: Vec<Hand> = content.lines().map(|line| parse_line(&line)).collect();
+/// Return the total winnings of the hands
+pub fn solve_part2(hands: &[Hand]) -> u32 {
+    let mut hands: Vec<&Hand> = hands.iter().collect();
     hands.sort();
     let mut result = 0;
     for (i, hand) in hands.iter().enumerate() {
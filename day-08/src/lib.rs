@@ -0,0 +1,250 @@
+//! Solution to day 8 of Advent of Code 2023: Haunted Wasteland
+use aoc_common::{Line, ParseError, RawInput, Solution};
+use num::integer::lcm;
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_parse_line() {
+        let input = RawInput::from("AAA = (BBB, CCC)\nAAA = BBB, CCC\nAAA (BBB, CCC)");
+        let mut lines = input.lines();
+        let labels = parse_line(&lines.next().unwrap()).unwrap();
+        assert_eq!(labels, ("AAA", "BBB", "CCC"));
+        let error = parse_line(&lines.next().unwrap()).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 7));
+        assert_eq!(error.token, "BBB, CCC");
+        let error = parse_line(&lines.next().unwrap()).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 15));
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        let input = RawInput::from("\n\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)");
+        let error = parse_input(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.message, "expected at least one movement");
+        let input = RawInput::from("LR\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)");
+        let error = parse_input(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 8));
+        assert_eq!(error.token, "BBB");
+        assert_eq!(error.message, "undefined node");
+    }
+
+    #[test]
+    fn test_part1() {
+        let fname = String::from("data/test_input_1");
+        let input = Day08::parse_file(&fname).unwrap();
+        let result = Day08::part1(&input);
+        assert_eq!(result, Some(2));
+        let fname = String::from("data/test_input_2");
+        let input = Day08::parse_file(&fname).unwrap();
+        let result = Day08::part1(&input);
+        assert_eq!(result, Some(6));
+        // Example of the second part, without the nodes of the first one
+        let fname = String::from("data/test_input_3");
+        let input = Day08::parse_file(&fname).unwrap();
+        assert_eq!(Day08::part1(&input), None);
+        // Goal that can't be reached from the start
+        let input = RawInput::from("L\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)");
+        let network = parse_input(&input).unwrap();
+        assert_eq!(Day08::part1(&network), None);
+    }
+
+    #[test]
+    fn test_part2() {
+        let fname = String::from("data/test_input_3");
+        let input = Day08::parse_file(&fname).unwrap();
+        let result = Day08::part2(&input);
+        assert_eq!(result, Some(6));
+    }
+}
+
+/// Node of the network, with the nodes it leads to
+#[derive(Debug)]
+pub struct Node {
+    pub left: String,
+    pub right: String,
+}
+
+impl Node {
+    /// Return the node reached after following a movement
+    pub fn move_to(&self, movement: &Movement) -> String {
+        match movement {
+            Movement::Left => self.left.clone(),
+            Movement::Right => self.right.clone(),
+        }
+    }
+}
+
+/// Instruction to follow the left or right node
+#[derive(Debug)]
+pub enum Movement {
+    Left,
+    Right,
+}
+
+/// Network of nodes along with the movements to follow through it
+#[derive(Debug)]
+pub struct Network {
+    /// Nodes of the network, indexed by their label
+    pub map: HashMap<String, Node>,
+    /// Movements to follow, repeated once they run out
+    pub movements: Vec<Movement>,
+}
+
+/// Solution to the puzzle of day 8
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Network;
+    type Answer = u64;
+
+    fn parse(input: &RawInput) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(network: &Self::Input) -> Option<u64> {
+        solve_part1(network)
+    }
+
+    fn part2(network: &Self::Input) -> Option<u64> {
+        Some(solve_part2(network))
+    }
+}
+
+/// Parse the network out of the puzzle input
+pub fn parse_input(input: &RawInput) -> Result<Network, ParseError> {
+    let mut lines = input.lines();
+    // Read movements
+    let line = match lines.next() {
+        Some(line) => line,
+        None => return Err(input.error_at_end("expected the list of movements")),
+    };
+    let mut movements = vec![];
+    for (i, c) in line.text.char_indices() {
+        match c {
+            'L' => movements.push(Movement::Left),
+            'R' => movements.push(Movement::Right),
+            _ => {
+                let token = &line.text[i..i + c.len_utf8()];
+                return Err(line.error(token, "invalid movement, expected 'L' or 'R'"));
+            }
+        }
+    }
+    if movements.is_empty() {
+        return Err(line.error_at_end("expected at least one movement"));
+    }
+    // Read empty line
+    match lines.next() {
+        Some(line) if line.text.is_empty() => (),
+        Some(line) => return Err(line.error(line.text, "expected empty line")),
+        None => return Err(input.error_at_end("expected empty line")),
+    }
+    // Read map
+    let mut map = HashMap::new();
+    let mut nodes = vec![];
+    for line in lines {
+        let (position, left, right) = parse_line(&line)?;
+        let node = Node {
+            left: String::from(left),
+            right: String::from(right),
+        };
+        map.insert(String::from(position), node);
+        nodes.push((line, left, right));
+    }
+    // Check that every node leads to nodes of the network
+    for (line, left, right) in nodes {
+        for label in [left, right] {
+            if !map.contains_key(label) {
+                return Err(line.error(label, "undefined node"));
+            }
+        }
+    }
+    Ok(Network { map, movements })
+}
+
+/// Split a line like `AAA = (BBB, CCC)` into the labels of the node and of
+/// the nodes to its left and right
+fn parse_line<'a>(line: &Line<'a>) -> Result<(&'a str, &'a str, &'a str), ParseError> {
+    let (position, nodes) = match line.text.split_once("=") {
+        Some(parts) => parts,
+        None => return Err(line.error_at_end("expected '=' after the node")),
+    };
+    let nodes = nodes.trim();
+    let nodes = match nodes.strip_prefix("(").and_then(|x| x.strip_suffix(")")) {
+        Some(nodes) => nodes,
+        None => return Err(line.error(nodes, "expected '(<left>, <right>)'")),
+    };
+    let (left, right) = match nodes.split_once(",") {
+        Some(nodes) => nodes,
+        None => return Err(line.error(nodes, "expected '<left>, <right>'")),
+    };
+    Ok((position.trim(), left.trim(), right.trim()))
+}
+
+fn solve_part1(network: &Network) -> Option<u64> {
+    let Network { map, movements } = network;
+    let mut position = String::from("AAA");
+    let goal = String::from("ZZZ");
+    if !map.contains_key(&position) || !map.contains_key(&goal) {
+        return None;
+    }
+    // Once every node has been left with every movement, the following ones
+    // go around in circles and the goal can't be reached anymore
+    let max_movements = map.len() * movements.len();
+    for (i, movement) in movements.iter().cycle().take(max_movements).enumerate() {
+        position = map[&position].move_to(movement);
+        if position == goal {
+            return Some(i as u64 + 1);
+        }
+    }
+    None
+}
+
+fn solve_part2(network: &Network) -> u64 {
+    let Network { map, movements } = network;
+    // Get initial positions
+    let initial_positions: Vec<String> = map.keys().filter(|x| x.ends_with("A")).cloned().collect();
+    // Explore graph for each initial position
+    let n_movements: Vec<u64> = initial_positions
+        .iter()
+        .map(|p| get_movements_until_goal(p, map, movements))
+        .collect();
+    let mut result = 1;
+    for item in n_movements.iter() {
+        result = lcm(result, *item);
+    }
+    result
+}
+
+/// Count the movements needed to reach a node ending in `Z`
+///
+/// # Arguments
+///
+/// * `position` - label of the starting node
+/// * `map` - nodes of the network
+/// * `movements` - movements to follow, repeated once they run out
+pub fn get_movements_until_goal(
+    position: &str,
+    map: &HashMap<String, Node>,
+    movements: &[Movement],
+) -> u64 {
+    let mut position = position.to_string();
+    let mut n_movements = 0;
+    let mut goal_reached = false;
+    while !goal_reached {
+        for movement in movements.iter() {
+            position = map.get(&position).unwrap().move_to(movement);
+            n_movements += 1;
+            // Check if goal was reached
+            if position.ends_with("Z") {
+                goal_reached = true;
+                break;
+            }
+        }
+    }
+    n_movements
+}
@@ -0,0 +1,135 @@
+//! Solution to day 9 of Advent of Code 2023: Mirage Maintenance
+use aoc_common::{Line, ParseError, RawInput, Solution};
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_difference_table() {
+        let table = DifferenceTable::new(&[10, 13, 16, 21, 30, 45]);
+        assert_eq!(table.rows.len(), 5);
+        assert_eq!(table.rows[1], vec![3, 3, 5, 9, 15]);
+        assert_eq!(table.rows[3], vec![2, 2, 2]);
+        assert_eq!(table.rows[4], vec![0, 0]);
+        assert_eq!(table.extrapolate(), 68);
+        assert_eq!(table.extrapolate_backward(), 5);
+    }
+
+    #[test]
+    fn test_difference_table_negative_values() {
+        let table = DifferenceTable::new(&[5, 1, -3, -7]);
+        assert_eq!(table.extrapolate(), -11);
+        assert_eq!(table.extrapolate_backward(), 9);
+        let table = DifferenceTable::new(&[7]);
+        assert_eq!(table.extrapolate(), 7);
+        assert_eq!(table.extrapolate_backward(), 7);
+    }
+
+    #[test]
+    fn test_parse_line_errors() {
+        let input = RawInput::from("0 3 6 9 12 15\n1 3 x 10");
+        let mut lines = input.lines();
+        let values = parse_line(&lines.next().unwrap()).unwrap();
+        assert_eq!(values, vec![0, 3, 6, 9, 12, 15]);
+        let error = parse_line(&lines.next().unwrap()).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+        assert_eq!(error.token, "x");
+    }
+
+    #[test]
+    fn test_part1() {
+        let fname = String::from("data/test_input");
+        let input = Day09::parse_file(&fname).unwrap();
+        let result = Day09::part1(&input);
+        assert_eq!(result, Some(114));
+    }
+
+    #[test]
+    fn test_part2() {
+        let fname = String::from("data/test_input");
+        let input = Day09::parse_file(&fname).unwrap();
+        let result = Day09::part2(&input);
+        assert_eq!(result, Some(2));
+    }
+}
+
+/// Sequence of values along with its successive differences
+///
+/// The first row holds the values of the sequence, and every following row
+/// the differences between consecutive elements of the previous one. The
+/// table stops at the first row made only of zeros.
+#[derive(Debug)]
+pub struct DifferenceTable {
+    pub rows: Vec<Vec<i64>>,
+}
+
+impl DifferenceTable {
+    /// Build the table of differences of a sequence
+    pub fn new(values: &[i64]) -> Self {
+        let mut rows = vec![values.to_vec()];
+        loop {
+            let last = rows.last().unwrap();
+            if last.iter().all(|v| *v == 0) {
+                break;
+            }
+            let diff: Vec<i64> = last.windows(2).map(|w| w[1] - w[0]).collect();
+            rows.push(diff);
+        }
+        Self { rows }
+    }
+
+    /// Return the next value of the sequence
+    pub fn extrapolate(&self) -> i64 {
+        let mut value = 0;
+        for row in self.rows.iter().rev() {
+            value += row.last().copied().unwrap_or(0);
+        }
+        value
+    }
+
+    /// Return the value that precedes the sequence
+    pub fn extrapolate_backward(&self) -> i64 {
+        let mut value = 0;
+        for row in self.rows.iter().rev() {
+            value = row.first().copied().unwrap_or(0) - value;
+        }
+        value
+    }
+}
+
+/// Parse a sequence of values out of a line like `0 3 6 9 12 15`
+pub fn parse_line(line: &Line) -> Result<Vec<i64>, ParseError> {
+    line.text
+        .split_whitespace()
+        .map(|x| x.parse().map_err(|_| line.error(x, "invalid number")))
+        .collect()
+}
+
+/// Solution to the puzzle of day 9
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<DifferenceTable>;
+    type Answer = i64;
+
+    fn parse(input: &RawInput) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|line| Ok(DifferenceTable::new(&parse_line(&line)?)))
+            .collect()
+    }
+
+    fn part1(tables: &Self::Input) -> Option<i64> {
+        Some(tables.iter().map(|table| table.extrapolate()).sum())
+    }
+
+    fn part2(tables: &Self::Input) -> Option<i64> {
+        Some(
+            tables
+                .iter()
+                .map(|table| table.extrapolate_backward())
+                .sum(),
+        )
+    }
+}
@@ -0,0 +1,13 @@
+use aoc_common::{Error, Solution};
+use day_09::Day09;
+
+fn main() -> Result<(), Error> {
+    let input = Day09::parse_file("data/input")?;
+    if let Some(result) = Day09::part1(&input) {
+        println!("Solution to part 1: {}", result);
+    }
+    if let Some(result) = Day09::part2(&input) {
+        println!("Solution to part 2: {}", result);
+    }
+    Ok(())
+}
@@ -0,0 +1,675 @@
+//! Solution to day 10 of Advent of Code 2023: Pipe Maze
+use aoc_common::{Line, ParseError, RawInput, Solution};
+use std::collections::VecDeque;
+use std::error;
+use std::fmt;
+
+pub mod render;
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_part1() {
+        let fname = String::from("data/test_input_1");
+        let input = Day10::parse_file(&fname).unwrap();
+        let result = Day10::part1(&input);
+        assert_eq!(result, Some(4));
+        let fname = String::from("data/test_input_2");
+        let input = Day10::parse_file(&fname).unwrap();
+        let result = Day10::part1(&input);
+        assert_eq!(result, Some(8));
+    }
+
+    #[test]
+    fn test_part2() {
+        let cases = [(3, 4), (4, 4), (5, 8), (6, 10)];
+        for (example, expected) in cases {
+            let fname = format!("data/test_input_{}", example);
+            let input = Day10::parse_file(&fname).unwrap();
+            let result = Day10::part2(&input);
+            assert_eq!(result, Some(expected), "example: {}", example);
+        }
+    }
+
+    #[test]
+    fn test_find_loop() {
+        let fname = String::from("data/test_input_1");
+        let map = Day10::parse_file(&fname).unwrap();
+        let polygon = map.find_loop();
+        assert_eq!(polygon.len(), 8);
+        assert_eq!(polygon[0], map.start_position);
+        assert_eq!(polygon[1], Position { x: 1, y: 2 });
+        assert_eq!(polygon[7], Position { x: 2, y: 1 });
+        assert_eq!(count_enclosed_tiles(&polygon), 1);
+    }
+
+    #[test]
+    fn test_enclosed_tiles() {
+        for example in [1, 3, 4, 5, 6] {
+            let fname = format!("data/test_input_{}", example);
+            let map = Day10::parse_file(&fname).unwrap();
+            let polygon = map.find_loop();
+            let tiles = map.enclosed_tiles(&polygon);
+            assert_eq!(tiles.len() as u32, count_enclosed_tiles(&polygon));
+        }
+        let fname = String::from("data/test_input_4");
+        let map = Day10::parse_file(&fname).unwrap();
+        let tiles = map.enclosed_tiles(&map.find_loop());
+        let expected = [(2, 6), (3, 6), (6, 6), (7, 6)];
+        let expected: Vec<Position> = expected
+            .iter()
+            .map(|(x, y)| Position { x: *x, y: *y })
+            .collect();
+        assert_eq!(tiles, expected);
+    }
+
+    #[test]
+    fn test_distances() {
+        let fname = String::from("data/test_input_1");
+        let map = Day10::parse_file(&fname).unwrap();
+        let field = map.distances();
+        assert_eq!(field.get(&map.start_position), Some(0));
+        assert_eq!(field.get(&Position { x: 3, y: 1 }), Some(2));
+        assert_eq!(field.get(&Position { x: 2, y: 2 }), None);
+        assert_eq!(field.get(&Position { x: 9, y: 9 }), None);
+        assert_eq!(field.max(), 4);
+        assert_eq!(field.farthest(), vec![Position { x: 3, y: 3 }]);
+        // Junk pipes aren't reachable from the start
+        let fname = String::from("data/test_input_6");
+        let map = Day10::parse_file(&fname).unwrap();
+        let field = map.distances();
+        assert_eq!(field.get(&Position { x: 0, y: 0 }), None);
+        for example in [2, 3, 4, 5, 6] {
+            let fname = format!("data/test_input_{}", example);
+            let map = Day10::parse_file(&fname).unwrap();
+            let field = map.distances();
+            let polygon = map.find_loop();
+            let n = polygon.len();
+            for (i, position) in polygon.iter().enumerate() {
+                assert_eq!(field.get(position), Some(i.min(n - i) as u32));
+            }
+            assert_eq!(field.farthest(), vec![polygon[n / 2]]);
+        }
+    }
+
+    #[test]
+    fn test_parse_input_error() {
+        let input = RawInput::from(".....\n.S-7.\n.|.|.\n.L-X.\n.....");
+        let error = parse_input(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 4));
+        assert_eq!(error.token, "X");
+        let input = RawInput::from("F-S\n|\nL-J");
+        let error = parse_input(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.message, "expected a row of 3 tiles");
+    }
+
+    #[test]
+    fn test_start_pipe() {
+        let cases = [
+            (1, Pipe::BendSE),
+            (3, Pipe::BendSE),
+            (5, Pipe::BendSE),
+            (6, Pipe::BendSW),
+        ];
+        for (example, expected) in cases {
+            let fname = format!("data/test_input_{}", example);
+            let map = Day10::parse_file(&fname).unwrap();
+            assert_eq!(
+                *map.get(&map.start_position),
+                expected,
+                "example: {}",
+                example
+            );
+        }
+        let input = RawInput::from(".F-7.\n.|.|.\n.S-J.");
+        let map = parse_input(&input).unwrap();
+        assert_eq!(*map.get(&map.start_position), Pipe::BendNE);
+    }
+
+    #[test]
+    fn test_start_errors() {
+        let cases = [
+            (
+                ".....\n.S...\n.....",
+                (2, 2),
+                "start isn't connected to any pipe",
+            ),
+            (
+                ".....\n.S-..\n.....",
+                (2, 2),
+                "start is connected to a single pipe",
+            ),
+            (
+                "..|..\n.-S-.\n..|..",
+                (3, 3),
+                "pipe at (2, 2) points off the grid",
+            ),
+            (
+                "F-7..\n|.|..\nL-S-7\n..|.|\n..L-J",
+                (3, 3),
+                "branch at (2, 2), several loops go through it",
+            ),
+            (".S-7.\n.|.|.\n.L-..", (3, 4), "dead end at (3, 2)"),
+            (".S-7.\n.|.|.\n.LFJ.\n.....", (3, 2), "dead end at (1, 2)"),
+            (".S-7.\n.|.|.\n.L-S.", (3, 4), "duplicated start"),
+            (".F-7.\n.|.|.\n.L-J.", (4, 1), "expected a start 'S'"),
+        ];
+        for (text, position, message) in cases {
+            let input = RawInput::from(text);
+            let error = parse_input(&input).unwrap_err();
+            assert_eq!((error.line, error.column), position, "input: {}", text);
+            assert_eq!(error.message, message);
+        }
+    }
+
+    #[test]
+    fn test_map_walk() {
+        let fname = String::from("data/test_input_1");
+        let map = Day10::parse_file(&fname).unwrap();
+        let new_position = map.walk(&Position { x: 3, y: 1 }, &Position { x: 2, y: 1 });
+        assert_eq!(new_position, Ok(Position { x: 3, y: 2 }));
+        let ground = Position { x: 0, y: 0 };
+        let error = map.walk(&ground, &Position { x: 1, y: 0 });
+        assert_eq!(error, Err(LoopError::DeadEnd(ground)));
+        // Coming from a tile the pipe doesn't connect to
+        let error = map.walk(&Position { x: 3, y: 1 }, &Position { x: 3, y: 0 });
+        assert_eq!(error, Err(LoopError::DeadEnd(Position { x: 3, y: 0 })));
+    }
+
+    #[test]
+    fn test_trace_loop_ambiguous_start() {
+        let input = RawInput::from(".....\n-S-7.\n.|.|.\n.L-J.\n.....");
+        let map = parse_input(&input).unwrap();
+        assert_eq!(*map.get(&map.start_position), Pipe::BendSE);
+        assert_eq!(map.get_starting_directions().len(), 3);
+        let polygon = map.trace_loop().unwrap();
+        assert_eq!(polygon.len(), 8);
+        assert_eq!(Day10::part1(&map), Some(4));
+    }
+
+    #[test]
+    fn test_map_starting_positions() {
+        let fname = String::from("data/test_input_1");
+        let map = Day10::parse_file(&fname).unwrap();
+        let starting_positions = map.get_starting_directions();
+        let expected = vec![Position { x: 1, y: 2 }, Position { x: 2, y: 1 }];
+        assert_eq!(expected, starting_positions);
+    }
+}
+
+/// Map of the pipes in the field
+#[derive(Debug)]
+pub struct Map {
+    /// Pipes of the field, indexed by row and column
+    pub map: Vec<Vec<Pipe>>,
+    pub start_position: Position,
+}
+
+impl Map {
+    /// Return the pipe at the given position
+    pub fn get(&self, position: &Position) -> &Pipe {
+        &self.map[position.y][position.x]
+    }
+
+    /// Return the neighbours of the start that have pipes connected to it
+    pub fn get_starting_directions(&self) -> Vec<Position> {
+        let mut positions = vec![];
+        // Move north
+        if self.start_position.y > 0 {
+            let x = self.start_position.x;
+            let y = self.start_position.y - 1;
+            let neighbour = Position { x, y };
+            match self.get(&neighbour) {
+                Pipe::Vertical | Pipe::BendSE | Pipe::BendSW => positions.push(neighbour),
+                _ => (),
+            }
+        }
+        // Move south
+        if self.start_position.y < self.map.len() - 1 {
+            let x = self.start_position.x;
+            let y = self.start_position.y + 1;
+            let neighbour = Position { x, y };
+            match self.get(&neighbour) {
+                Pipe::Vertical | Pipe::BendNE | Pipe::BendNW => positions.push(neighbour),
+                _ => (),
+            }
+        }
+        // Move east
+        if self.start_position.x < self.map[0].len() - 1 {
+            let x = self.start_position.x + 1;
+            let y = self.start_position.y;
+            let neighbour = Position { x, y };
+            match self.get(&neighbour) {
+                Pipe::Horizontal | Pipe::BendSW | Pipe::BendNW => positions.push(neighbour),
+                _ => (),
+            }
+        }
+        // Move west
+        if self.start_position.x > 0 {
+            let x = self.start_position.x - 1;
+            let y = self.start_position.y;
+            let neighbour = Position { x, y };
+            match self.get(&neighbour) {
+                Pipe::Horizontal | Pipe::BendSE | Pipe::BendNE => positions.push(neighbour),
+                _ => (),
+            }
+        }
+        positions
+    }
+
+    /// Return the positions of the two tiles the pipe at a position connects
+    fn ends(&self, position: &Position) -> Result<[Position; 2], LoopError> {
+        let (first, second) = match self.get(position).ends() {
+            Some(ends) => ends,
+            None => return Err(LoopError::DeadEnd(*position)),
+        };
+        let neighbour = |(dx, dy): (i32, i32)| {
+            let x = position.x.checked_add_signed(dx as isize)?;
+            let y = position.y.checked_add_signed(dy as isize)?;
+            let row = self.map.get(y)?;
+            (x < row.len()).then_some(Position { x, y })
+        };
+        match (neighbour(first), neighbour(second)) {
+            (Some(first), Some(second)) => Ok([first, second]),
+            _ => Err(LoopError::OffGrid(*position)),
+        }
+    }
+
+    /// Follow the pipe at a position to the next one
+    ///
+    /// Fails if the position has no pipe, if the pipe points off the grid,
+    /// or if it isn't connected to the tile we came from.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - position of the pipe being followed
+    /// * `avoid` - position we came from, so we don't walk back to it
+    pub fn walk(&self, position: &Position, avoid: &Position) -> Result<Position, LoopError> {
+        let [first, second] = self.ends(position)?;
+        if first == *avoid {
+            Ok(second)
+        } else if second == *avoid {
+            Ok(first)
+        } else {
+            // The pipe we came from leads to one that doesn't connect back
+            Err(LoopError::DeadEnd(*avoid))
+        }
+    }
+
+    /// Trace the loop that goes through the start
+    ///
+    /// Returns the positions of its pipes in the order they're walked,
+    /// starting from the start, or the reason why they don't form a loop.
+    pub fn trace_loop(&self) -> Result<Vec<Position>, LoopError> {
+        let start = self.start_position;
+        let [first, last] = self.ends(&start)?;
+        let mut polygon = vec![start];
+        let mut previous = start;
+        let mut position = first;
+        while position != start {
+            polygon.push(position);
+            let next = self.walk(&position, &previous)?;
+            previous = position;
+            position = next;
+        }
+        // The loop must get back to the start through its other end
+        if previous != last {
+            return Err(LoopError::DeadEnd(previous));
+        }
+        Ok(polygon)
+    }
+
+    /// Return the positions of the pipes of the loop that goes through the
+    /// start, in the order they're walked starting from it
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pipes don't form a loop, which the parser already
+    /// checks.
+    pub fn find_loop(&self) -> Vec<Position> {
+        self.trace_loop()
+            .expect("pipes should form a loop through the start")
+    }
+
+    /// Return whether every tile of the field belongs to a loop, given by
+    /// the positions of its pipes
+    pub fn loop_mask(&self, polygon: &[Position]) -> Vec<Vec<bool>> {
+        let mut mask: Vec<Vec<bool>> = self.map.iter().map(|row| vec![false; row.len()]).collect();
+        for position in polygon {
+            mask[position.y][position.x] = true;
+        }
+        mask
+    }
+
+    /// Return the tiles enclosed by a loop, row by row
+    ///
+    /// Each row is scanned from west to east, crossing the loop every time a
+    /// pipe of the loop connects to the north. Tiles reached after an odd
+    /// number of crossings are inside the loop.
+    pub fn enclosed_tiles(&self, polygon: &[Position]) -> Vec<Position> {
+        let mask = self.loop_mask(polygon);
+        let mut tiles = vec![];
+        for (y, row) in self.map.iter().enumerate() {
+            let mut inside = false;
+            for (x, pipe) in row.iter().enumerate() {
+                if mask[y][x] {
+                    if matches!(pipe, Pipe::Vertical | Pipe::BendNE | Pipe::BendNW) {
+                        inside = !inside;
+                    }
+                } else if inside {
+                    tiles.push(Position { x, y });
+                }
+            }
+        }
+        tiles
+    }
+
+    /// Compute the distance from the start to every tile of the loop
+    ///
+    /// Runs a breadth-first search from the start, moving between pipes that
+    /// connect to each other.
+    pub fn distances(&self) -> DistanceField {
+        let mut distances: Vec<Vec<Option<u32>>> =
+            self.map.iter().map(|row| vec![None; row.len()]).collect();
+        let start = self.start_position;
+        distances[start.y][start.x] = Some(0);
+        let mut queue = VecDeque::from([(start, 0)]);
+        while let Some((position, distance)) = queue.pop_front() {
+            let ends = match self.ends(&position) {
+                Ok(ends) => ends,
+                Err(_) => continue,
+            };
+            for next in ends {
+                let connected = self.ends(&next).is_ok_and(|e| e.contains(&position));
+                if connected && distances[next.y][next.x].is_none() {
+                    distances[next.y][next.x] = Some(distance + 1);
+                    queue.push_back((next, distance + 1));
+                }
+            }
+        }
+        DistanceField { distances }
+    }
+}
+
+/// Distance from the start to every tile, through the pipes of the loop
+#[derive(Debug)]
+pub struct DistanceField {
+    /// Distances indexed by row and column, `None` for unreachable tiles
+    pub distances: Vec<Vec<Option<u32>>>,
+}
+
+impl DistanceField {
+    /// Return the distance to a tile, or `None` if it can't be reached
+    pub fn get(&self, position: &Position) -> Option<u32> {
+        *self.distances.get(position.y)?.get(position.x)?
+    }
+
+    /// Return the distance to the farthest tile
+    pub fn max(&self) -> u32 {
+        self.distances
+            .iter()
+            .flatten()
+            .flatten()
+            .copied()
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Return the positions of the farthest tiles, row by row
+    pub fn farthest(&self) -> Vec<Position> {
+        let max = self.max();
+        let mut positions = vec![];
+        for (y, row) in self.distances.iter().enumerate() {
+            for (x, distance) in row.iter().enumerate() {
+                if *distance == Some(max) {
+                    positions.push(Position { x, y });
+                }
+            }
+        }
+        positions
+    }
+}
+
+/// Count the tiles enclosed by a loop, given by the ordered positions of its
+/// pipes
+///
+/// The area of the loop is computed with the shoelace formula, taking the
+/// centres of the tiles as its vertices. Pick's theorem then gives the number
+/// of tiles inside it out of its area and the number of tiles on its border,
+/// so tiles squeezed between pipes are not counted.
+pub fn count_enclosed_tiles(polygon: &[Position]) -> u32 {
+    let mut double_area: i64 = 0;
+    for (i, a) in polygon.iter().enumerate() {
+        let b = &polygon[(i + 1) % polygon.len()];
+        double_area += a.x as i64 * b.y as i64 - b.x as i64 * a.y as i64;
+    }
+    let n_border = polygon.len() as i64;
+    ((double_area.abs() - n_border) / 2 + 1) as u32
+}
+
+/// Reason why the pipes don't form a loop through the start
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoopError {
+    /// The path stops at the position, as its tile isn't connected to the
+    /// next one
+    DeadEnd(Position),
+    /// The pipe at the position points outside the field
+    OffGrid(Position),
+    /// Several loops go through the start at the position
+    Branch(Position),
+}
+
+impl LoopError {
+    /// Position of the tile where the loop breaks
+    pub fn position(&self) -> Position {
+        match self {
+            LoopError::DeadEnd(position)
+            | LoopError::OffGrid(position)
+            | LoopError::Branch(position) => *position,
+        }
+    }
+}
+
+impl fmt::Display for LoopError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Position { x, y } = self.position();
+        match self {
+            LoopError::DeadEnd(_) => write!(f, "dead end at ({}, {})", x, y),
+            LoopError::OffGrid(_) => write!(f, "pipe at ({}, {}) points off the grid", x, y),
+            LoopError::Branch(_) => {
+                write!(f, "branch at ({}, {}), several loops go through it", x, y)
+            }
+        }
+    }
+}
+
+impl error::Error for LoopError {}
+
+/// Position in the field, `y` grows towards the south
+#[derive(Copy, Clone, Debug)]
+pub struct Position {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Eq for Position {}
+
+impl PartialEq for Position {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+}
+
+/// Tile of the field
+///
+/// The start is replaced by the pipe that connects it to its neighbours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pipe {
+    Vertical,
+    Horizontal,
+    BendNE,
+    BendNW,
+    BendSW,
+    BendSE,
+    Ground,
+}
+
+impl Pipe {
+    /// Offsets `(dx, dy)` to the two tiles the pipe connects, or `None` for
+    /// the ground
+    pub fn ends(&self) -> Option<((i32, i32), (i32, i32))> {
+        match self {
+            Pipe::Vertical => Some(((0, 1), (0, -1))),
+            Pipe::Horizontal => Some(((-1, 0), (1, 0))),
+            Pipe::BendNE => Some(((0, -1), (1, 0))),
+            Pipe::BendNW => Some(((0, -1), (-1, 0))),
+            Pipe::BendSW => Some(((0, 1), (-1, 0))),
+            Pipe::BendSE => Some(((0, 1), (1, 0))),
+            Pipe::Ground => None,
+        }
+    }
+
+    /// Box-drawing character that represents the pipe
+    pub fn symbol(&self) -> char {
+        match self {
+            Pipe::Vertical => '│',
+            Pipe::Horizontal => '─',
+            Pipe::BendNE => '└',
+            Pipe::BendNW => '┘',
+            Pipe::BendSW => '┐',
+            Pipe::BendSE => '┌',
+            Pipe::Ground => '.',
+        }
+    }
+}
+
+/// Solution to the puzzle of day 10
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Map;
+    type Answer = u32;
+
+    fn parse(input: &RawInput) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(map: &Self::Input) -> Option<u32> {
+        Some(solve_part1(map))
+    }
+
+    fn part2(map: &Self::Input) -> Option<u32> {
+        Some(count_enclosed_tiles(&map.find_loop()))
+    }
+}
+
+/// Parse the map of pipes out of the puzzle input
+pub fn parse_input(input: &RawInput) -> Result<Map, ParseError> {
+    let mut start = None;
+    let mut map: Vec<Vec<Pipe>> = vec![];
+    let lines: Vec<Line> = input.lines().collect();
+    for (i, line) in lines.iter().enumerate() {
+        let mut row: Vec<Pipe> = vec![];
+        for (j, (k, char)) in line.text.char_indices().enumerate() {
+            let value = match char {
+                '|' => Pipe::Vertical,
+                '-' => Pipe::Horizontal,
+                'L' => Pipe::BendNE,
+                'J' => Pipe::BendNW,
+                '7' => Pipe::BendSW,
+                'F' => Pipe::BendSE,
+                '.' => Pipe::Ground,
+                'S' => {
+                    let token = &line.text[k..k + 1];
+                    if start.is_some() {
+                        return Err(line.error(token, "duplicated start"));
+                    }
+                    start = Some((*line, token, Position { x: j, y: i }));
+                    // Placeholder until the neighbours of the start are known
+                    Pipe::Ground
+                }
+                _ => {
+                    let token = &line.text[k..k + char.len_utf8()];
+                    return Err(line.error(token, "invalid pipe"));
+                }
+            };
+            row.push(value);
+        }
+        if let Some(first) = map.first() {
+            if row.len() != first.len() {
+                let message = format!("expected a row of {} tiles", first.len());
+                return Err(line.error(line.text, &message));
+            }
+        }
+        map.push(row);
+    }
+    let (line, token, start_position) = match start {
+        Some(start) => start,
+        None => return Err(input.error_at_end("expected a start 'S'")),
+    };
+    let mut map = Map {
+        map,
+        start_position,
+    };
+    let neighbours = map.get_starting_directions();
+    match neighbours.len() {
+        0 => return Err(line.error(token, "start isn't connected to any pipe")),
+        1 => return Err(line.error(token, "start is connected to a single pipe")),
+        _ => (),
+    }
+    // Keep the pipes under the start that close a loop, trying every pair of
+    // neighbours connected to it
+    let mut pipes = vec![];
+    let mut first_error = None;
+    for (i, first) in neighbours.iter().enumerate() {
+        for second in neighbours[i + 1..].iter() {
+            let pipe = connecting_pipe(&start_position, &[*first, *second]);
+            map.map[start_position.y][start_position.x] = pipe;
+            match map.trace_loop() {
+                Ok(_) => pipes.push(pipe),
+                Err(error) => {
+                    first_error.get_or_insert(error);
+                }
+            }
+        }
+    }
+    let error = match pipes[..] {
+        [pipe] => {
+            map.map[start_position.y][start_position.x] = pipe;
+            return Ok(map);
+        }
+        [] => first_error.unwrap(),
+        _ => LoopError::Branch(start_position),
+    };
+    // Pipes are single characters, so the column of a tile is its position
+    let Position { x, y } = error.position();
+    let line = &lines[y];
+    Err(line.error(&line.text[x..x + 1], &error.to_string()))
+}
+
+/// Return the pipe that connects a position to two of its neighbours
+fn connecting_pipe(position: &Position, neighbours: &[Position]) -> Pipe {
+    let connects = |dx: i32, dy: i32| {
+        neighbours
+            .iter()
+            .any(|n| n.x as i32 - position.x as i32 == dx && n.y as i32 - position.y as i32 == dy)
+    };
+    let (north, south) = (connects(0, -1), connects(0, 1));
+    let (east, west) = (connects(1, 0), connects(-1, 0));
+    match (north, south, east, west) {
+        (true, true, false, false) => Pipe::Vertical,
+        (false, false, true, true) => Pipe::Horizontal,
+        (true, false, true, false) => Pipe::BendNE,
+        (true, false, false, true) => Pipe::BendNW,
+        (false, true, false, true) => Pipe::BendSW,
+        (false, true, true, false) => Pipe::BendSE,
+        _ => panic!("Expected two different neighbours"),
+    }
+}
+
+fn solve_part1(map: &Map) -> u32 {
+    map.distances().max()
+}
@@ -0,0 +1,134 @@
+//! Render the map of pipes with box-drawing characters
+use crate::{Map, Pipe};
+use std::fmt::Write;
+
+#[cfg(test)]
+mod tests {
+    use crate::render::*;
+    use crate::Day10;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_render() {
+        let fname = String::from("data/test_input_1");
+        let map = Day10::parse_file(&fname).unwrap();
+        let options = RenderOptions::default();
+        let expected = ".....\n.┌─┐.\n.│.│.\n.└─┘.\n.....\n";
+        assert_eq!(render(&map, &options), expected);
+        let options = RenderOptions {
+            mark_inside: true,
+            ..Default::default()
+        };
+        let expected = "OOOOO\nO┌─┐O\nO│I│O\nO└─┘O\nOOOOO\n";
+        assert_eq!(render(&map, &options), expected);
+    }
+
+    #[test]
+    fn test_render_heatmap() {
+        let fname = String::from("data/test_input_1");
+        let map = Day10::parse_file(&fname).unwrap();
+        let options = RenderOptions {
+            ansi: true,
+            heatmap: true,
+            ..Default::default()
+        };
+        let rendered = render(&map, &options);
+        let second_line = rendered.lines().nth(1).unwrap();
+        // The start is the closest tile, and the farthest one is on the
+        // opposite corner
+        assert!(second_line.starts_with(".\x1b[38;5;21m┌\x1b[0m"));
+        let fourth_line = rendered.lines().nth(3).unwrap();
+        assert!(fourth_line.contains("\x1b[38;5;196m┘\x1b[0m"));
+        let options = RenderOptions {
+            heatmap: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            render(&map, &options),
+            render(&map, &RenderOptions::default())
+        );
+    }
+
+    #[test]
+    fn test_render_ansi() {
+        let fname = String::from("data/test_input_6");
+        let map = Day10::parse_file(&fname).unwrap();
+        let options = RenderOptions {
+            ansi: true,
+            ..Default::default()
+        };
+        let rendered = render(&map, &options);
+        let first_line = rendered.lines().next().unwrap();
+        // The first tile is a junk pipe, the second one belongs to the loop
+        assert!(first_line.starts_with("\x1b[2m┌\x1b[0m\x1b[1;33m┌\x1b[0m"));
+        assert_eq!(rendered.lines().count(), 10);
+    }
+}
+
+/// How to render the map of pipes
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RenderOptions {
+    /// Use ANSI escape codes to highlight the loop and dim the junk pipes
+    pub ansi: bool,
+    /// Replace the tiles that aren't part of the loop by `I` if they're
+    /// enclosed by it, or by `O` otherwise
+    pub mark_inside: bool,
+    /// Colour the loop by the distance of its pipes to the start, from blue
+    /// to red (only with ANSI escape codes)
+    pub heatmap: bool,
+}
+
+const LOOP: &str = "\x1b[1;33m";
+const JUNK: &str = "\x1b[2m";
+const INSIDE: &str = "\x1b[1;32m";
+const OUTSIDE: &str = "\x1b[34m";
+const RESET: &str = "\x1b[0m";
+
+/// Colours of the heatmap, from the closest tiles to the farthest ones
+const HEATMAP: [u8; 11] = [21, 27, 33, 39, 45, 51, 226, 220, 214, 208, 196];
+
+/// ANSI escape code of the colour of a distance in the heatmap
+fn heat(distance: u32, max: u32) -> String {
+    let index = distance as usize * (HEATMAP.len() - 1) / max.max(1) as usize;
+    format!("\x1b[38;5;{}m", HEATMAP[index])
+}
+
+/// Render the map with box-drawing characters, one line per row
+pub fn render(map: &Map, options: &RenderOptions) -> String {
+    let polygon = map.find_loop();
+    let on_loop = map.loop_mask(&polygon);
+    let field = map.distances();
+    let max = field.max();
+    let mut inside = map.loop_mask(&[]);
+    if options.mark_inside {
+        for position in map.enclosed_tiles(&polygon) {
+            inside[position.y][position.x] = true;
+        }
+    }
+    let mut rendered = String::new();
+    for (y, row) in map.map.iter().enumerate() {
+        for (x, pipe) in row.iter().enumerate() {
+            let (symbol, style) = if on_loop[y][x] && options.heatmap {
+                let distance = field.distances[y][x].unwrap_or(0);
+                (pipe.symbol(), heat(distance, max))
+            } else if on_loop[y][x] {
+                (pipe.symbol(), String::from(LOOP))
+            } else if options.mark_inside && inside[y][x] {
+                ('I', String::from(INSIDE))
+            } else if options.mark_inside {
+                ('O', String::from(OUTSIDE))
+            } else if *pipe == Pipe::Ground {
+                (pipe.symbol(), String::new())
+            } else {
+                (pipe.symbol(), String::from(JUNK))
+            };
+            if options.ansi && !style.is_empty() {
+                write!(rendered, "{}{}{}", style, symbol, RESET).unwrap();
+            } else {
+                rendered.push(symbol);
+            }
+        }
+        rendered.push('\n');
+    }
+    rendered
+}
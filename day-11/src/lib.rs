@@ -0,0 +1,179 @@
+//! Solution to day 11 of Advent of Code 2023: Cosmic Expansion
+use aoc_common::{ParseError, RawInput, Solution};
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_part1() {
+        let fname = String::from("data/test_input");
+        let input = Day11::parse_file(&fname).unwrap();
+        let result = Day11::part1(&input);
+        assert_eq!(result, Some(374));
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        let input = RawInput::from("...#\n.#x.");
+        let error = parse_input(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.token, "x");
+        let input = RawInput::from("...#\n.#.");
+        let error = parse_input(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.message, "expected a row of 4 tiles");
+        let input = RawInput::from("");
+        let error = parse_input(&input).unwrap_err();
+        assert_eq!(error.message, "expected the image");
+    }
+
+    #[test]
+    fn test_no_galaxies() {
+        let galaxies = parse_input(&RawInput::from("...\n...")).unwrap();
+        assert_eq!(Day11::part1(&galaxies), Some(0));
+    }
+
+    #[test]
+    fn test_solution_any_factor() {
+        let fname = String::from("data/test_input");
+        let galaxies = Day11::parse_file(&fname).unwrap();
+        let result = solution(&galaxies, &10);
+        assert_eq!(result, 1030);
+        let result = solution(&galaxies, &100);
+        assert_eq!(result, 8410);
+    }
+}
+
+/// Galaxy observed in the image
+#[derive(Debug, Clone)]
+pub struct Galaxy {
+    pub x: u64,
+    pub y: u64,
+}
+
+impl Galaxy {
+    /// Return the Manhattan distance to another galaxy
+    pub fn distance(&self, other: &Galaxy) -> u64 {
+        let dx = (self.x as i32 - other.x as i32).abs();
+        let dy = (self.y as i32 - other.y as i32).abs();
+        (dx + dy) as u64
+    }
+}
+
+/// Solution to the puzzle of day 11
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Galaxy>;
+    type Answer = u64;
+
+    fn parse(input: &RawInput) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(galaxies: &Self::Input) -> Option<u64> {
+        Some(solution(galaxies, &2))
+    }
+
+    fn part2(galaxies: &Self::Input) -> Option<u64> {
+        Some(solution(galaxies, &1_000_000))
+    }
+}
+
+/// Return the galaxies (`#`) found in the image
+pub fn parse_input(input: &RawInput) -> Result<Vec<Galaxy>, ParseError> {
+    let mut galaxies = vec![];
+    let mut width = None;
+    for (y, line) in input.lines().enumerate() {
+        let mut row_width = 0;
+        for (x, (i, char)) in line.text.char_indices().enumerate() {
+            match char {
+                '#' => galaxies.push(Galaxy {
+                    x: x as u64,
+                    y: y as u64,
+                }),
+                '.' => (),
+                _ => {
+                    let token = &line.text[i..i + char.len_utf8()];
+                    return Err(line.error(token, "invalid character, expected '#' or '.'"));
+                }
+            }
+            row_width += 1;
+        }
+        match width {
+            None => width = Some(row_width),
+            Some(width) if row_width != width => {
+                let message = format!("expected a row of {} tiles", width);
+                return Err(line.error(line.text, &message));
+            }
+            Some(_) => (),
+        }
+    }
+    if width.is_none() {
+        return Err(input.error_at_end("expected the image"));
+    }
+    Ok(galaxies)
+}
+
+/// Move the galaxies to account for the expansion of the universe
+///
+/// # Arguments
+///
+/// * `galaxies` - galaxies observed in the image
+/// * `factor` - number of times each empty row and column gets expanded
+pub fn expand_galaxies(galaxies: &mut [Galaxy], factor: &u64) {
+    if galaxies.is_empty() {
+        return;
+    }
+    let mut xs: Vec<u64> = galaxies.iter().map(|g| g.x).collect();
+    xs.sort();
+    let mut ys: Vec<u64> = galaxies.iter().map(|g| g.y).collect();
+    ys.sort();
+    let missing_x: Vec<u64> = (xs[0]..*xs.iter().last().unwrap())
+        .filter(|&x| !xs.contains(&{ x }))
+        .collect();
+    let missing_y: Vec<u64> = (ys[0]..*ys.iter().last().unwrap())
+        .filter(|&y| !ys.contains(&{ y }))
+        .collect();
+    for galaxy in galaxies.iter_mut() {
+        let n_xs = {
+            let mut n = 0;
+            for x in missing_x.iter() {
+                if x < &galaxy.x {
+                    n += 1
+                };
+            }
+            n
+        };
+        let n_ys = {
+            let mut n = 0;
+            for y in missing_y.iter() {
+                if y < &galaxy.y {
+                    n += 1
+                };
+            }
+            n
+        };
+        galaxy.x += n_xs * (factor - 1);
+        galaxy.y += n_ys * (factor - 1);
+    }
+}
+
+/// Sum the distances between every pair of galaxies
+///
+/// # Arguments
+///
+/// * `galaxies` - galaxies observed in the image, before the expansion
+/// * `factor` - number of times each empty row and column gets expanded
+pub fn solution(galaxies: &[Galaxy], factor: &u64) -> u64 {
+    let mut galaxies = galaxies.to_vec();
+    expand_galaxies(&mut galaxies, factor);
+    let mut distances = 0;
+    for i in 0..galaxies.len() {
+        for j in i..galaxies.len() {
+            distances += galaxies[i].distance(&galaxies[j])
+        }
+    }
+    distances
+}
@@ -0,0 +1,228 @@
+//! Solution to day 12 of Advent of Code 2023: Hot Springs
+use aoc_common::{Line, ParseError, RawInput, Solution};
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn record(springs: &str, groups: &[usize]) -> Record {
+        let input = RawInput::from(springs);
+        let springs = match input.lines().next() {
+            Some(line) => parse_springs(&line, line.text).unwrap(),
+            None => vec![],
+        };
+        Record {
+            springs,
+            groups: groups.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_possible_arrangements() {
+        let cases: [(&str, &[usize], u64); 5] = [
+            ("?##", &[3], 1),
+            ("??#", &[3], 1),
+            ("???", &[3], 1),
+            ("?#??..#", &[3, 1], 2),
+            ("?#??.#", &[3, 1], 2),
+        ];
+        for (springs, groups, expected) in cases {
+            let result = record(springs, groups).n_arrangements();
+            assert_eq!(result, expected, "springs: {}", springs);
+        }
+    }
+
+    #[test]
+    fn test_arrangements_edge_cases() {
+        assert_eq!(record("", &[]).n_arrangements(), 1);
+        assert_eq!(record("", &[1]).n_arrangements(), 0);
+        assert_eq!(record("..?", &[]).n_arrangements(), 1);
+        assert_eq!(record(".#.", &[]).n_arrangements(), 0);
+        assert_eq!(record("##", &[1]).n_arrangements(), 0);
+        assert_eq!(record("????", &[1, 1]).n_arrangements(), 3);
+    }
+
+    #[test]
+    fn test_unfold() {
+        let unfolded = record(".#", &[1]).unfold(5);
+        assert_eq!(unfolded, record(".#?.#?.#?.#?.#", &[1, 1, 1, 1, 1]));
+        let unfolded = record("???.###", &[1, 1, 3]).unfold(5);
+        assert_eq!(unfolded.n_arrangements(), 1);
+        let unfolded = record("?###????????", &[3, 2, 1]).unfold(5);
+        assert_eq!(unfolded.n_arrangements(), 506250);
+    }
+
+    #[test]
+    fn test_parse_line_errors() {
+        let input = RawInput::from("???.### 1,1,3\n??x.### 1,1,3\n???.### 1,a,3\n???.###");
+        let mut lines = input.lines();
+        let record = parse_line(&lines.next().unwrap()).unwrap();
+        assert_eq!(record.groups, vec![1, 1, 3]);
+        assert_eq!(record.springs[3], Spring::Operational);
+        let error = parse_line(&lines.next().unwrap()).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.token, "x");
+        let error = parse_line(&lines.next().unwrap()).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 11));
+        assert_eq!(error.token, "a");
+        let error = parse_line(&lines.next().unwrap()).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 8));
+    }
+
+    #[test]
+    fn test_part1() {
+        let fname = String::from("data/test_input");
+        let input = Day12::parse_file(&fname).unwrap();
+        let result = Day12::part1(&input);
+        assert_eq!(result, Some(21));
+    }
+
+    #[test]
+    fn test_part2() {
+        let fname = String::from("data/test_input");
+        let input = Day12::parse_file(&fname).unwrap();
+        let result = Day12::part2(&input);
+        assert_eq!(result, Some(525152));
+    }
+}
+
+/// Condition of a spring
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Spring {
+    /// Operational spring (`.`)
+    Operational,
+    /// Damaged spring (`#`)
+    Damaged,
+    /// Spring whose condition is unknown (`?`)
+    Unknown,
+}
+
+/// Row of springs along with the sizes of its groups of damaged springs
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub springs: Vec<Spring>,
+    pub groups: Vec<usize>,
+}
+
+impl Record {
+    /// Return the record repeated a number of times
+    ///
+    /// Copies of the springs are joined by an unknown spring, while copies of
+    /// the groups are simply concatenated.
+    pub fn unfold(&self, times: usize) -> Self {
+        let mut springs = vec![];
+        for i in 0..times {
+            if i > 0 {
+                springs.push(Spring::Unknown);
+            }
+            springs.extend_from_slice(&self.springs);
+        }
+        Record {
+            springs,
+            groups: self.groups.repeat(times),
+        }
+    }
+
+    /// Check if the group of damaged springs can start at the given position
+    ///
+    /// The group fits if none of its springs is operational and it's not
+    /// followed by a damaged spring, which would make it longer.
+    fn fits_group(&self, start: usize, size: usize) -> bool {
+        let end = start + size;
+        if end > self.springs.len() {
+            return false;
+        }
+        if self.springs[start..end].contains(&Spring::Operational) {
+            return false;
+        }
+        self.springs.get(end) != Some(&Spring::Damaged)
+    }
+
+    /// Count the arrangements of springs that agree with the groups
+    ///
+    /// Fills a table where `table[i][j]` holds the number of arrangements of
+    /// the springs from the `i`-th one onwards that agree with the groups
+    /// from the `j`-th one onwards. The table is filled from the end of the
+    /// row backwards, so every cell only depends on cells already computed.
+    pub fn n_arrangements(&self) -> u64 {
+        let n_springs = self.springs.len();
+        let n_groups = self.groups.len();
+        let mut table = vec![vec![0_u64; n_groups + 1]; n_springs + 1];
+        // Once every spring is placed, only running out of groups is valid
+        table[n_springs][n_groups] = 1;
+        for i in (0..n_springs).rev() {
+            for j in (0..=n_groups).rev() {
+                let spring = self.springs[i];
+                let mut result = 0;
+                // Treat unknown springs as operational ones
+                if spring != Spring::Damaged {
+                    result += table[i + 1][j];
+                }
+                // Treat unknown springs as the start of the next group, which
+                // needs to be followed by an operational spring (if any)
+                if spring != Spring::Operational && j < n_groups {
+                    let size = self.groups[j];
+                    if self.fits_group(i, size) {
+                        let next = (i + size + 1).min(n_springs);
+                        result += table[next][j + 1];
+                    }
+                }
+                table[i][j] = result;
+            }
+        }
+        table[0][0]
+    }
+}
+
+/// Parse the conditions of a row of springs like `???.###`
+fn parse_springs(line: &Line, springs: &str) -> Result<Vec<Spring>, ParseError> {
+    let mut result = vec![];
+    for (i, c) in springs.char_indices() {
+        let spring = match c {
+            '.' => Spring::Operational,
+            '#' => Spring::Damaged,
+            '?' => Spring::Unknown,
+            _ => {
+                let token = &springs[i..i + c.len_utf8()];
+                return Err(line.error(token, "invalid spring, expected '.', '#' or '?'"));
+            }
+        };
+        result.push(spring);
+    }
+    Ok(result)
+}
+
+/// Parse a record out of a line like `???.### 1,1,3`
+pub fn parse_line(line: &Line) -> Result<Record, ParseError> {
+    let (springs, groups) = match line.text.split_once(' ') {
+        Some(parts) => parts,
+        None => return Err(line.error_at_end("expected sizes of the groups")),
+    };
+    let springs = parse_springs(line, springs)?;
+    let groups = groups
+        .split(',')
+        .map(|x| x.parse().map_err(|_| line.error(x, "invalid group size")))
+        .collect::<Result<Vec<usize>, ParseError>>()?;
+    Ok(Record { springs, groups })
+}
+
+/// Solution to the puzzle of day 12
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Record>;
+    type Answer = u64;
+
+    fn parse(input: &RawInput) -> Result<Self::Input, ParseError> {
+        input.lines().map(|line| parse_line(&line)).collect()
+    }
+
+    fn part1(records: &Self::Input) -> Option<u64> {
+        Some(records.iter().map(|record| record.n_arrangements()).sum())
+    }
+
+    fn part2(records: &Self::Input) -> Option<u64> {
+        let unfolded = records.iter().map(|record| record.unfold(5));
+        Some(unfolded.map(|record| record.n_arrangements()).sum())
+    }
+}
@@ -0,0 +1,13 @@
+use aoc_common::{Error, Solution};
+use day_12::Day12;
+
+fn main() -> Result<(), Error> {
+    let input = Day12::parse_file("data/input")?;
+    if let Some(result) = Day12::part1(&input) {
+        println!("Solution to part 1: {}", result);
+    }
+    if let Some(result) = Day12::part2(&input) {
+        println!("Solution to part 2: {}", result);
+    }
+    Ok(())
+}
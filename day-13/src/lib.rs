@@ -0,0 +1,320 @@
+//! Solution to day 13 of Advent of Code 2023: Point of Incidence
+use aoc_common::{ParseError, RawInput, Solution};
+use std::cmp;
+use std::error;
+use std::fmt;
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_palindrome() {
+        let vec: Vec<u32> = vec![1, 2, 2, 1, 3, 4];
+        assert_eq!(count_mismatches(&vec, 0, 5), 3);
+        assert_eq!(count_mismatches(&vec, 0, 3), 0);
+        let vec: Vec<u32> = vec![1, 2, 1, 1];
+        assert_eq!(count_mismatches(&vec, 0, 3), 1);
+        // let vec: Vec<u32> = vec![1, 2, 2, 1];
+        // assert_eq!(is_palindrome(&vec), true);
+        // let vec: Vec<u32> = vec![1, 2, 3, 5, 5, 3, 2, 1];
+        // assert_eq!(is_palindrome(&vec), true);
+    }
+
+    #[test]
+    fn test_parse_input_error() {
+        let input = RawInput::from("#.##\n..#.\n\n##..\n#.o#\n");
+        let error = parse_input(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (5, 3));
+        assert_eq!(error.token, "o");
+        let input = RawInput::from("#.##..##.\n..#.##.#\n##......#");
+        let error = parse_input(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.message, "expected a row of 9 tiles");
+    }
+
+    #[test]
+    fn test_part1() {
+        let fname = String::from("data/test_input");
+        let input = Day13::parse_file(&fname).unwrap();
+        let result = Day13::part1(&input);
+        assert_eq!(result, Some(405));
+    }
+
+    #[test]
+    fn test_find_symmetry_plane_with_smudges() {
+        let fname = String::from("data/test_input");
+        let patterns = Day13::parse_file(&fname).unwrap();
+        assert_eq!(find_symmetry_plane(&patterns[0], &true, 0), Some(5));
+        assert_eq!(find_symmetry_plane(&patterns[0], &false, 0), None);
+        assert_eq!(find_symmetry_plane(&patterns[0], &false, 1), Some(3));
+        assert_eq!(find_symmetry_plane(&patterns[1], &false, 0), Some(4));
+        assert_eq!(find_symmetry_plane(&patterns[1], &false, 1), Some(1));
+        assert_eq!(find_symmetry_plane(&patterns[1], &true, 1), None);
+    }
+
+    #[test]
+    fn test_summarize() {
+        let fname = String::from("data/test_input");
+        let patterns = Day13::parse_file(&fname).unwrap();
+        assert_eq!(summarize(&patterns, 0), Ok(405));
+        assert_eq!(summarize(&patterns, 1), Ok(400));
+        assert_eq!(summarize(&patterns, 2), Ok(8));
+        assert_eq!(summarize(&patterns, 3), Err(SummaryError::NoPlane(0)));
+        assert_eq!(
+            summarize(&patterns, 5),
+            Err(SummaryError::AmbiguousPlanes(0))
+        );
+        assert_eq!(summarize(&patterns[1..], 5), Ok(8));
+        assert_eq!(summarize(&patterns, 100), Err(SummaryError::NoPlane(0)));
+    }
+
+    #[test]
+    fn test_find_symmetry_planes() {
+        let fname = String::from("data/test_input");
+        let patterns = Day13::parse_file(&fname).unwrap();
+        assert_eq!(find_symmetry_planes(&patterns[0], &true, 0), vec![5]);
+        assert_eq!(find_symmetry_planes(&patterns[0], &false, 5), vec![1, 6]);
+        assert_eq!(find_symmetry_plane(&patterns[0], &false, 5), None);
+        assert_eq!(find_symmetry_planes(&patterns[1], &true, 3), vec![1, 3]);
+        assert_eq!(find_symmetry_planes(&patterns[1], &true, 4), vec![]);
+    }
+
+    #[test]
+    fn test_part2() {
+        let fname = String::from("data/test_input");
+        let input = Day13::parse_file(&fname).unwrap();
+        let result = Day13::part2(&input);
+        assert_eq!(result, Some(400));
+    }
+}
+
+/// Pattern of ash and rocks
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    /// Rows of the pattern, rocks (`#`) are `true` and ash (`.`) is `false`
+    pub pattern: Vec<Vec<bool>>,
+}
+
+impl Pattern {
+    /// Return the pattern with its rows and columns swapped
+    pub fn transpose(&self) -> Self {
+        assert!(!self.pattern.is_empty());
+        let transposed = (0..self.pattern[0].len())
+            .map(|i| {
+                self.pattern
+                    .iter()
+                    .map(|inner| inner[i])
+                    .collect::<Vec<bool>>()
+            })
+            .collect();
+        Self {
+            pattern: transposed,
+        }
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut rows: Vec<String> = vec![];
+        for row in self.pattern.iter() {
+            rows.push(
+                row.iter()
+                    .map(|v| match v {
+                        true => "#",
+                        false => ".",
+                    })
+                    .collect(),
+            );
+        }
+        writeln!(f, "{}", rows.join("\n"))
+    }
+}
+
+/// Count the pairs of elements that break the palindromy of a subsequence
+///
+/// The subsequence goes from `start` to `end` (both included) and it's a
+/// palindrome if no pair of elements mismatch.
+fn count_mismatches<T: Eq>(sequence: &[T], start: usize, end: usize) -> usize {
+    if (end - start).is_multiple_of(2) {
+        panic!("Found sequence with odd number of elements.");
+    };
+    if end >= sequence.len() {
+        panic!("End larger than the length of the sequence");
+    }
+    if start >= end {
+        panic!("Start cannot be larger or equal than end");
+    }
+    let mut mismatches = 0;
+    let (mut start, mut end) = (start, end);
+    while start < end {
+        if sequence[start] != sequence[end] {
+            mismatches += 1;
+        }
+        start += 1;
+        end -= 1;
+    }
+    mismatches
+}
+
+/// Find every plane that reflects the pattern
+///
+/// Each plane is given by the number of columns to its left (if vertical) or
+/// the number of rows above it (if horizontal). Smudges are cells that don't
+/// match their reflection: the planes must have exactly as many of them as
+/// requested. Several planes can qualify when there are smudges.
+///
+/// # Arguments
+///
+/// * `pattern` - pattern of ash and rocks
+/// * `vertical` - look for vertical planes if true, or horizontal ones if
+///   false
+/// * `smudges` - number of cells that don't match their reflection
+pub fn find_symmetry_planes(pattern: &Pattern, vertical: &bool, smudges: usize) -> Vec<usize> {
+    let length = match *vertical {
+        true => pattern.pattern[0].len(),
+        false => pattern.pattern.len(),
+    };
+    let pattern = match *vertical {
+        true => pattern.clone(),
+        false => pattern.transpose(),
+    };
+    // Keep the candidate planes along with the mismatches found so far
+    let mut planes_stack: Vec<(usize, usize)> = (1..length).map(|plane| (plane, 0)).collect();
+    for row in pattern.pattern.iter() {
+        if planes_stack.is_empty() {
+            break;
+        }
+        let mut i = 0;
+        while i < planes_stack.len() {
+            let (plane, mismatches) = planes_stack.remove(i);
+            let (start, end) = get_start_end(&plane, &length);
+            let mismatches = mismatches + count_mismatches(row, start, end);
+            if mismatches <= smudges {
+                planes_stack.insert(i, (plane, mismatches));
+                i += 1;
+            }
+        }
+    }
+    planes_stack
+        .into_iter()
+        .filter(|(_, mismatches)| *mismatches == smudges)
+        .map(|(plane, _)| plane)
+        .collect()
+}
+
+/// Find the plane that reflects the pattern, if there is exactly one
+///
+/// Returns `None` if no plane or more than one plane has the requested
+/// number of smudges, see [`find_symmetry_planes`].
+pub fn find_symmetry_plane(pattern: &Pattern, vertical: &bool, smudges: usize) -> Option<usize> {
+    match find_symmetry_planes(pattern, vertical, smudges)[..] {
+        [plane] => Some(plane),
+        _ => None,
+    }
+}
+
+fn get_start_end(plane: &usize, length: &usize) -> (usize, usize) {
+    let delta = cmp::min(*plane, *length - *plane);
+    let start = *plane - delta;
+    let end = *plane + delta - 1;
+    (start, end)
+}
+
+/// Solution to the puzzle of day 13
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Pattern>;
+    type Answer = u32;
+
+    fn parse(input: &RawInput) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(patterns: &Self::Input) -> Option<u32> {
+        summarize(patterns, 0).ok()
+    }
+
+    fn part2(patterns: &Self::Input) -> Option<u32> {
+        summarize(patterns, 1).ok()
+    }
+}
+
+/// Parse the patterns out of the puzzle input
+pub fn parse_input(input: &RawInput) -> Result<Vec<Pattern>, ParseError> {
+    let mut patterns = vec![];
+    for block in input.blocks() {
+        let mut pattern: Vec<Vec<bool>> = vec![];
+        for line in block.iter() {
+            let mut row = vec![];
+            for (i, c) in line.text.char_indices() {
+                match c {
+                    '#' => row.push(true),
+                    '.' => row.push(false),
+                    _ => {
+                        let token = &line.text[i..i + c.len_utf8()];
+                        return Err(line.error(token, "invalid character, expected '#' or '.'"));
+                    }
+                }
+            }
+            if let Some(first) = pattern.first() {
+                if row.len() != first.len() {
+                    let message = format!("expected a row of {} tiles", first.len());
+                    return Err(line.error(line.text, &message));
+                }
+            }
+            pattern.push(row);
+        }
+        patterns.push(Pattern { pattern });
+    }
+    Ok(patterns)
+}
+
+/// Reason why the reflections of the patterns can't be summarized
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SummaryError {
+    /// No plane reflects the pattern with the given index
+    NoPlane(usize),
+    /// Several planes reflect the pattern with the given index
+    AmbiguousPlanes(usize),
+}
+
+impl fmt::Display for SummaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SummaryError::NoPlane(i) => write!(f, "no plane reflects pattern {}", i + 1),
+            SummaryError::AmbiguousPlanes(i) => {
+                write!(f, "several planes reflect pattern {}", i + 1)
+            }
+        }
+    }
+}
+
+impl error::Error for SummaryError {}
+
+/// Summarize the reflections of the patterns
+///
+/// Adds up the columns to the left of each vertical plane and 100 times the
+/// rows above each horizontal one. Every pattern must be reflected by a
+/// single plane, either vertical or horizontal, with the given number of
+/// smudges.
+///
+/// # Arguments
+///
+/// * `patterns` - patterns of ash and rocks
+/// * `smudges` - number of cells that don't match their reflection
+pub fn summarize(patterns: &[Pattern], smudges: usize) -> Result<u32, SummaryError> {
+    let mut result = 0;
+    for (i, pattern) in patterns.iter().enumerate() {
+        let vertical = find_symmetry_planes(pattern, &true, smudges);
+        let horizontal = find_symmetry_planes(pattern, &false, smudges);
+        match (&vertical[..], &horizontal[..]) {
+            ([plane], []) => result += *plane as u32,
+            ([], [plane]) => result += 100 * *plane as u32,
+            ([], []) => return Err(SummaryError::NoPlane(i)),
+            _ => return Err(SummaryError::AmbiguousPlanes(i)),
+        }
+    }
+    Ok(result)
+}
@@ -0,0 +1,197 @@
+//! Export the tilts of the spin cycles as frames of an animation
+use crate::{Platform, Rock, SPIN_CYCLE};
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+#[cfg(test)]
+mod tests {
+    use crate::animation::*;
+    use crate::{parse_input, Day14, Direction};
+    use aoc_common::{RawInput, Solution};
+
+    fn platform(text: &str) -> Platform {
+        parse_input(&RawInput::from(text)).unwrap()
+    }
+
+    #[test]
+    fn test_frames() {
+        let fname = String::from("data/test_input");
+        let platform = Day14::parse_file(&fname).unwrap();
+        let frames = frames(&platform, 2);
+        assert_eq!(frames.len(), 9);
+        assert_eq!(frames[0], platform);
+        assert_eq!(frames[1], platform.tilted(Direction::North));
+        let mut spun = platform.clone();
+        spun.spin_cycle();
+        assert_eq!(frames[4], spun);
+        spun.spin_cycle();
+        assert_eq!(frames[8], spun);
+    }
+
+    #[test]
+    fn test_ansi_frame() {
+        let frame = ansi_frame(&platform("O#\n.O"));
+        let expected = "\x1b[1;33mO\x1b[0m\x1b[37m#\x1b[0m\n\x1b[2m.\x1b[0m\x1b[1;33mO\x1b[0m\n";
+        assert_eq!(frame, expected);
+    }
+
+    #[test]
+    fn test_play() {
+        let frames = frames(&platform("O.\n.#"), 1);
+        let mut output = vec![];
+        play(&mut output, &frames, Duration::ZERO).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.starts_with("\x1b[2J"));
+        assert_eq!(output.matches("\x1b[H").count(), 5);
+    }
+
+    #[test]
+    fn test_image() {
+        let platform = platform("O#\n..");
+        let mut expected = b"P5\n2 2\n255\n".to_vec();
+        expected.extend([255, 96, 0, 0]);
+        assert_eq!(image(&platform, ImageFormat::Pgm, 1), expected);
+        let image = image(&platform, ImageFormat::Ppm, 3);
+        assert!(image.starts_with(b"P6\n6 6\n255\n"));
+        assert_eq!(image.len(), b"P6\n6 6\n255\n".len() + 6 * 6 * 3);
+        assert_eq!(image[image.len() - 3..], [20, 20, 30]);
+    }
+
+    #[test]
+    fn test_save_images() {
+        let dir = std::env::temp_dir().join(format!("day-14-frames-{}", std::process::id()));
+        let frames = frames(&platform("O.\n.#"), 1);
+        save_images(&dir, &frames, ImageFormat::Pgm, 2).unwrap();
+        for i in 0..5 {
+            assert!(dir.join(format!("frame_{:04}.pgm", i)).exists());
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
+
+/// Return the platform before spinning and after every tilt of a number of
+/// spin cycles
+pub fn frames(platform: &Platform, n_cycles: usize) -> Vec<Platform> {
+    let mut frames = vec![platform.clone()];
+    let mut platform = platform.clone();
+    for _ in 0..n_cycles {
+        for direction in SPIN_CYCLE {
+            platform.tilt(direction);
+            frames.push(platform.clone());
+        }
+    }
+    frames
+}
+
+/// Write a frame with ANSI escape codes, highlighting the rounded rocks
+pub fn ansi_frame(platform: &Platform) -> String {
+    let mut frame = String::new();
+    for row in platform.tiles.iter() {
+        for rock in row {
+            let style = match rock {
+                Rock::Rounded => "\x1b[1;33m",
+                Rock::Cube => "\x1b[37m",
+                Rock::Empty => "\x1b[2m",
+            };
+            frame.push_str(style);
+            frame.push(rock.symbol());
+            frame.push_str("\x1b[0m");
+        }
+        frame.push('\n');
+    }
+    frame
+}
+
+/// Play the frames as an animation in a terminal
+///
+/// # Arguments
+///
+/// * `writer` - where the frames are written, usually the standard output
+/// * `frames` - frames to play, in order
+/// * `delay` - time each frame stays on screen
+pub fn play<W: Write>(writer: &mut W, frames: &[Platform], delay: Duration) -> io::Result<()> {
+    // Clear the screen once, then draw every frame over the previous one
+    write!(writer, "\x1b[2J")?;
+    for frame in frames {
+        write!(writer, "\x1b[H{}", ansi_frame(frame))?;
+        writer.flush()?;
+        thread::sleep(delay);
+    }
+    Ok(())
+}
+
+/// Format of the images of the frames
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    /// Binary grayscale image (`.pgm`)
+    Pgm,
+    /// Binary colour image (`.ppm`)
+    Ppm,
+}
+
+impl ImageFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ImageFormat::Pgm => "pgm",
+            ImageFormat::Ppm => "ppm",
+        }
+    }
+
+    /// Bytes of the pixels of a tile
+    fn pixel(&self, rock: &Rock) -> &'static [u8] {
+        match (self, rock) {
+            (ImageFormat::Pgm, Rock::Rounded) => &[255],
+            (ImageFormat::Pgm, Rock::Cube) => &[96],
+            (ImageFormat::Pgm, Rock::Empty) => &[0],
+            (ImageFormat::Ppm, Rock::Rounded) => &[230, 180, 40],
+            (ImageFormat::Ppm, Rock::Cube) => &[90, 90, 100],
+            (ImageFormat::Ppm, Rock::Empty) => &[20, 20, 30],
+        }
+    }
+}
+
+/// Draw a frame as a binary PGM or PPM image
+///
+/// # Arguments
+///
+/// * `platform` - frame to draw
+/// * `format` - format of the image
+/// * `scale` - size in pixels of the side of each tile
+pub fn image(platform: &Platform, format: ImageFormat, scale: usize) -> Vec<u8> {
+    let magic = match format {
+        ImageFormat::Pgm => "P5",
+        ImageFormat::Ppm => "P6",
+    };
+    let (width, height) = (platform.width() * scale, platform.height() * scale);
+    let mut image = format!("{}\n{} {}\n255\n", magic, width, height).into_bytes();
+    for row in platform.tiles.iter() {
+        for _ in 0..scale {
+            for rock in row {
+                for _ in 0..scale {
+                    image.extend_from_slice(format.pixel(rock));
+                }
+            }
+        }
+    }
+    image
+}
+
+/// Save every frame as an image named `frame_NNNN` in a directory, which is
+/// created if needed
+pub fn save_images<P: AsRef<Path>>(
+    dir: P,
+    frames: &[Platform],
+    format: ImageFormat,
+    scale: usize,
+) -> io::Result<()> {
+    let dir = dir.as_ref();
+    fs::create_dir_all(dir)?;
+    for (i, frame) in frames.iter().enumerate() {
+        let path = dir.join(format!("frame_{:04}.{}", i, format.extension()));
+        fs::write(path, image(frame, format, scale))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,372 @@
+//! Solution to day 14 of Advent of Code 2023: Parabolic Reflector Dish
+use aoc_common::{Line, ParseError, RawInput, Solution};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::fmt;
+
+pub mod animation;
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn platform(text: &str) -> Platform {
+        parse_input(&RawInput::from(text)).unwrap()
+    }
+
+    #[test]
+    fn test_tilt() {
+        let mut tilted = platform("O.#.\n.O..\n#..O\n..O.");
+        tilted.tilt(Direction::North);
+        assert_eq!(tilted, platform("OO#O\n..O.\n#...\n...."));
+        let mut tilted = platform("O.#.\n.O..\n#..O\n..O.");
+        tilted.tilt(Direction::South);
+        assert_eq!(tilted, platform("..#.\nO...\n#...\n.OOO"));
+        let mut tilted = platform("O.#.\n.O..\n#..O\n..O.");
+        tilted.tilt(Direction::West);
+        assert_eq!(tilted, platform("O.#.\nO...\n#O..\nO..."));
+        let mut tilted = platform("O.#.\n.O..\n#..O\n..O.");
+        tilted.tilt(Direction::East);
+        assert_eq!(tilted, platform(".O#.\n...O\n#..O\n...O"));
+    }
+
+    #[test]
+    fn test_spin_cycle() {
+        let fname = String::from("data/test_input");
+        let mut platform = Day14::parse_file(&fname).unwrap();
+        platform.spin_cycle();
+        let expected = ".....#....\n....#...O#\n...OO##...\n.OO#......\n.....OOO#.\n\
+                        .O#...O#.#\n....O#....\n......OOOO\n#...O###..\n#..OO#....";
+        assert_eq!(platform, parse_input(&RawInput::from(expected)).unwrap());
+        let mut spun = platform.clone();
+        spun.spin(2);
+        platform.spin_cycle();
+        platform.spin_cycle();
+        assert_eq!(spun, platform);
+    }
+
+    #[test]
+    fn test_tilted() {
+        let fname = String::from("data/test_input");
+        let platform = Day14::parse_file(&fname).unwrap();
+        let tilted = platform.tilted(Direction::North);
+        let expected = "OOOO.#.O..\nOO..#....#\nOO..O##..O\nO..#.OO...\n........#.\n\
+                        ..#....#.#\n..O..#.O.O\n..O.......\n#....###..\n#....#....";
+        assert_eq!(tilted.to_string(), expected);
+        assert_eq!(tilted.load(), 136);
+        assert_eq!(platform.load(), 104);
+        // Tilting doesn't change the original platform
+        assert_eq!(platform, Day14::parse_file(&fname).unwrap());
+        let mut spun = platform.clone();
+        spun.spin_cycle();
+        let tilted = platform
+            .tilted(Direction::North)
+            .tilted(Direction::West)
+            .tilted(Direction::South)
+            .tilted(Direction::East);
+        assert_eq!(tilted, spun);
+    }
+
+    #[test]
+    fn test_display() {
+        let fname = String::from("data/test_input");
+        let platform = Day14::parse_file(&fname).unwrap();
+        let content = std::fs::read_to_string(&fname).unwrap();
+        assert_eq!(platform.to_string(), content.trim_end());
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let fname = String::from("data/test_input");
+        let platform = Day14::parse_file(&fname).unwrap();
+        let (first, period) = platform.find_cycle();
+        assert_eq!((first, period), (3, 7));
+        let mut start = platform.clone();
+        start.spin(first);
+        let mut repeated = start.clone();
+        repeated.spin(period);
+        assert_eq!(start, repeated);
+        let mut unchanged = platform.clone();
+        unchanged.spin(0);
+        assert_eq!(unchanged, platform);
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        let input = RawInput::from("O.#.\n.Ox.");
+        let error = parse_input(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.token, "x");
+        let input = RawInput::from("O.#.\n.O.");
+        let error = parse_input(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.message, "expected a row of 4 tiles");
+        let input = RawInput::from("");
+        let error = parse_input(&input).unwrap_err();
+        assert_eq!(error.message, "expected the platform");
+    }
+
+    #[test]
+    fn test_part1() {
+        let fname = String::from("data/test_input");
+        let input = Day14::parse_file(&fname).unwrap();
+        let result = Day14::part1(&input);
+        assert_eq!(result, Some(136));
+    }
+
+    #[test]
+    fn test_part2() {
+        let fname = String::from("data/test_input");
+        let input = Day14::parse_file(&fname).unwrap();
+        let result = Day14::part2(&input);
+        assert_eq!(result, Some(64));
+    }
+}
+
+/// Tile of the platform
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rock {
+    /// Rounded rock (`O`), which rolls when the platform is tilted
+    Rounded,
+    /// Cube-shaped rock (`#`), which stays in place
+    Cube,
+    /// Empty space (`.`)
+    Empty,
+}
+
+/// Direction the platform can be tilted towards
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    North,
+    West,
+    South,
+    East,
+}
+
+/// Directions the platform is tilted towards in a spin cycle, in order
+pub const SPIN_CYCLE: [Direction; 4] = [
+    Direction::North,
+    Direction::West,
+    Direction::South,
+    Direction::East,
+];
+
+/// Platform with rocks on it, which can be tilted to make them roll
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Platform {
+    /// Tiles of the platform, indexed by row and column
+    pub tiles: Vec<Vec<Rock>>,
+}
+
+impl Platform {
+    pub fn height(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn width(&self) -> usize {
+        self.tiles[0].len()
+    }
+
+    /// Return the row and column of a tile, given the line of tiles parallel
+    /// to the direction it's in and its distance to the edge rocks roll to
+    fn index(&self, direction: Direction, line: usize, distance: usize) -> (usize, usize) {
+        match direction {
+            Direction::North => (distance, line),
+            Direction::South => (self.height() - 1 - distance, line),
+            Direction::West => (line, distance),
+            Direction::East => (line, self.width() - 1 - distance),
+        }
+    }
+
+    /// Tilt the platform, making every rounded rock roll until it hits the
+    /// edge, a cube-shaped rock or another rounded rock
+    pub fn tilt(&mut self, direction: Direction) {
+        let (n_lines, len) = match direction {
+            Direction::North | Direction::South => (self.width(), self.height()),
+            Direction::West | Direction::East => (self.height(), self.width()),
+        };
+        for line in 0..n_lines {
+            // Closest place to the edge where the next rounded rock stops
+            let mut free = 0;
+            for distance in 0..len {
+                let (y, x) = self.index(direction, line, distance);
+                match self.tiles[y][x] {
+                    Rock::Cube => free = distance + 1,
+                    Rock::Rounded => {
+                        self.tiles[y][x] = Rock::Empty;
+                        let (y, x) = self.index(direction, line, free);
+                        self.tiles[y][x] = Rock::Rounded;
+                        free += 1;
+                    }
+                    Rock::Empty => (),
+                }
+            }
+        }
+    }
+
+    /// Return a copy of the platform tilted towards a direction
+    pub fn tilted(&self, direction: Direction) -> Platform {
+        let mut tilted = self.clone();
+        tilted.tilt(direction);
+        tilted
+    }
+
+    /// Tilt the platform north, west, south and east, in this order
+    pub fn spin_cycle(&mut self) {
+        for direction in SPIN_CYCLE {
+            self.tilt(direction);
+        }
+    }
+
+    /// Run spin cycles until an arrangement of the rocks repeats, or until
+    /// a number of cycles have run
+    ///
+    /// Returns the arrangements seen before the last one, along with the
+    /// number of cycles after which they were seen, the last arrangement, and
+    /// the number of cycles after which it was first seen if it's repeated.
+    fn arrangements(
+        &self,
+        max_cycles: usize,
+    ) -> (HashMap<Platform, usize>, Platform, Option<usize>) {
+        let mut seen: HashMap<Platform, usize> = HashMap::new();
+        let mut platform = self.clone();
+        for cycle in 0..max_cycles {
+            match seen.entry(platform.clone()) {
+                Entry::Occupied(entry) => {
+                    let previous = *entry.get();
+                    return (seen, platform, Some(previous));
+                }
+                Entry::Vacant(entry) => entry.insert(cycle),
+            };
+            platform.spin_cycle();
+        }
+        let previous = seen.get(&platform).copied();
+        (seen, platform, previous)
+    }
+
+    /// Find when the arrangements of the rocks start repeating
+    ///
+    /// Returns the number of spin cycles after which the first repeated
+    /// arrangement is reached, and the number of cycles it takes to come
+    /// back to it.
+    pub fn find_cycle(&self) -> (usize, usize) {
+        match self.arrangements(usize::MAX) {
+            (seen, _, Some(first)) => (first, seen.len() - first),
+            (_, _, None) => unreachable!("there's a finite number of arrangements"),
+        }
+    }
+
+    /// Run a number of spin cycles
+    ///
+    /// The rocks end up repeating the same arrangements over and over, so
+    /// once an arrangement is seen twice the cycles that bring it back are
+    /// skipped.
+    pub fn spin(&mut self, n_cycles: usize) {
+        let (seen, last, first) = self.arrangements(n_cycles);
+        *self = match first {
+            Some(first) => {
+                let period = seen.len() - first;
+                let target = first + (n_cycles - first) % period;
+                seen.into_iter()
+                    .find(|(_, cycle)| *cycle == target)
+                    .map(|(platform, _)| platform)
+                    .expect("every cycle before the repeat was seen")
+            }
+            None => last,
+        };
+    }
+
+    /// Return the load on the north beams, without tilting the platform
+    pub fn load(&self) -> u32 {
+        let mut load = 0;
+        for (i, row) in self.tiles.iter().enumerate() {
+            let n_rounded = row.iter().filter(|rock| **rock == Rock::Rounded).count();
+            load += (n_rounded * (self.height() - i)) as u32;
+        }
+        load
+    }
+}
+
+impl Rock {
+    /// Character that represents the tile in the puzzle input
+    pub fn symbol(&self) -> char {
+        match self {
+            Rock::Rounded => 'O',
+            Rock::Cube => '#',
+            Rock::Empty => '.',
+        }
+    }
+}
+
+/// Write the platform as in the puzzle input, one line per row
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.tiles.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            let row: String = row.iter().map(|rock| rock.symbol()).collect();
+            write!(f, "{}", row)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse a row of tiles like `O....#....`
+fn parse_row(line: &Line) -> Result<Vec<Rock>, ParseError> {
+    let mut row = vec![];
+    for (i, c) in line.text.char_indices() {
+        let rock = match c {
+            'O' => Rock::Rounded,
+            '#' => Rock::Cube,
+            '.' => Rock::Empty,
+            _ => {
+                let token = &line.text[i..i + c.len_utf8()];
+                return Err(line.error(token, "invalid rock, expected 'O', '#' or '.'"));
+            }
+        };
+        row.push(rock);
+    }
+    Ok(row)
+}
+
+/// Parse the platform out of the puzzle input
+pub fn parse_input(input: &RawInput) -> Result<Platform, ParseError> {
+    let mut tiles: Vec<Vec<Rock>> = vec![];
+    for line in input.lines() {
+        let row = parse_row(&line)?;
+        if let Some(first) = tiles.first() {
+            if row.len() != first.len() {
+                let message = format!("expected a row of {} tiles", first.len());
+                return Err(line.error(line.text, &message));
+            }
+        }
+        tiles.push(row);
+    }
+    if tiles.is_empty() {
+        return Err(input.error_at_end("expected the platform"));
+    }
+    Ok(Platform { tiles })
+}
+
+/// Solution to the puzzle of day 14
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Platform;
+    type Answer = u32;
+
+    fn parse(input: &RawInput) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(platform: &Self::Input) -> Option<u32> {
+        Some(platform.tilted(Direction::North).load())
+    }
+
+    fn part2(platform: &Self::Input) -> Option<u32> {
+        let mut platform = platform.clone();
+        platform.spin(1_000_000_000);
+        Some(platform.load())
+    }
+}
@@ -1,50 +1,53 @@
-use std::fs;
+use aoc_common::{Error, Solution};
+use day_14::animation::{self, ImageFormat};
+use day_14::{Day14, Platform};
+use std::env;
+use std::io;
+use std::process;
+use std::time::Duration;
 
-#[cfg(test)]
-mod tests {
-    use crate::*;
-
-    #[test]
-    fn test_part1() {
-        let fname = String::from("data/test_input");
-        let result = solve_part1(&fname);
-        assert_eq!(result, 136);
+/// Animate the spin cycles up to the first repeated arrangement and one
+/// more loop back to it, in the terminal or as images in a directory
+fn animate(platform: &Platform, args: &[String]) -> io::Result<()> {
+    let (first, period) = platform.find_cycle();
+    let frames = animation::frames(platform, first + period);
+    match args {
+        [] => animation::play(&mut io::stdout(), &frames, Duration::from_millis(100))?,
+        [flag, dir] if flag == "--pgm" || flag == "--ppm" => {
+            let format = match flag.as_str() {
+                "--pgm" => ImageFormat::Pgm,
+                _ => ImageFormat::Ppm,
+            };
+            animation::save_images(dir, &frames, format, 4)?;
+            println!("{} frames saved to {}", frames.len(), dir);
+        }
+        _ => {
+            eprintln!("usage: day-14 animate [--pgm DIR | --ppm DIR]");
+            process::exit(2);
+        }
     }
+    println!(
+        "Arrangements repeat every {} cycles, starting after {} cycles",
+        period, first
+    );
+    Ok(())
 }
 
-fn read_file(fname: &String) -> String {
-    let content = match fs::read_to_string(fname) {
-        Err(why) => panic!("could't open {}: {}", fname, why),
-        Ok(file_conent) => file_conent,
-    };
-    content
-}
-
-fn parse_file(fname: &String) -> Vec<String> {
-    let content = read_file(fname);
-    content.lines().map(|l| String::from(l)).collect()
-}
-
-fn solve_part1(fname: &String) -> u32 {
-    let map = parse_file(fname);
-    let n_rows = map.len() as u32;
-    let mut load: u32 = 0;
-    for j in 0..map[0].len() {
-        let column: String = map.iter().map(|row| row.chars().nth(j).unwrap()).collect();
-        let mut position = 0;
-        for portion in column.split("#") {
-            let n_rounded = portion.chars().filter(|c| *c == 'O').count() as u32;
-            if n_rounded > 0 {
-                load += n_rounded * (n_rows - position as u32) - (n_rounded - 1) * n_rounded / 2;
-            }
-            position += portion.len() + 1;
+fn main() -> Result<(), Error> {
+    let input = Day14::parse_file("data/input")?;
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("animate") {
+        if let Err(error) = animate(&input, &args[1..]) {
+            eprintln!("couldn't export the animation: {}", error);
+            process::exit(1);
         }
+        return Ok(());
     }
-    load
-}
-
-fn main() {
-    let fname = String::from("data/input");
-    let result = solve_part1(&fname);
-    println!("Solution to part 1: {}", result);
+    if let Some(result) = Day14::part1(&input) {
+        println!("Solution to part 1: {}", result);
+    }
+    if let Some(result) = Day14::part2(&input) {
+        println!("Solution to part 2: {}", result);
+    }
+    Ok(())
 }
@@ -0,0 +1,197 @@
+//! Solution to day 15 of Advent of Code 2023: Lens Library
+use std::collections::HashMap;
+
+use aoc_common::{Line, ParseError, RawInput, Solution};
+use indexmap::IndexMap;
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_parse_code() {
+        let input = RawInput::from("rn=1,cm-,qp=x,pc4");
+        let line = input.lines().next().unwrap();
+        let codes: Vec<&str> = line.text.split(",").collect();
+        let expected = (String::from("rn"), Some(1));
+        assert_eq!(parse_code(&line, codes[0]).unwrap(), expected);
+        let expected = (String::from("cm"), None);
+        assert_eq!(parse_code(&line, codes[1]).unwrap(), expected);
+        let error = parse_code(&line, codes[2]).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 13));
+        assert_eq!(error.token, "x");
+        let error = parse_code(&line, codes[3]).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 15));
+        assert_eq!(error.token, "pc4");
+    }
+
+    #[test]
+    fn test_part1() {
+        let fname = String::from("data/test_input");
+        let input = Day15::parse_file(&fname).unwrap();
+        let result = Day15::part1(&input);
+        assert_eq!(result, Some(1320));
+    }
+
+    #[test]
+    fn test_part2() {
+        let fname = String::from("data/test_input");
+        let input = Day15::parse_file(&fname).unwrap();
+        let result = Day15::part2(&input);
+        assert_eq!(result, Some(145));
+    }
+}
+
+/// Box holding lenses in the order they were inserted
+#[derive(Debug, Default)]
+pub struct LensBox {
+    lenses: IndexMap<String, u32>,
+}
+
+impl LensBox {
+    pub fn new() -> Self {
+        let lenses = IndexMap::new();
+        LensBox { lenses }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lenses.is_empty()
+    }
+
+    /// Insert a lens, replacing the one with the same label if present
+    pub fn insert(&mut self, lens: String, focal_lenght: u32) {
+        self.lenses.insert(lens, focal_lenght);
+    }
+
+    /// Remove the lens with the given label, if present
+    pub fn remove(&mut self, lens: String) {
+        self.lenses.shift_remove(&lens);
+    }
+
+    /// Return the focusing power of the lenses in the box, if it has any
+    pub fn focusing_power(&self) -> Option<u32> {
+        if self.lenses.is_empty() {
+            return None;
+        }
+        let box_index = {
+            let (label, _) = self.lenses.get_index(0).unwrap();
+            hash_algorithm(label) + 1 // sum 1 to start counting boxes from 1
+        };
+        let mut result = 0;
+        for (slot, (_, focal_length)) in self.lenses.iter().enumerate() {
+            result += box_index * (slot + 1) as u32 * focal_length;
+        }
+        Some(result)
+    }
+}
+
+/// Hash a string into a number between 0 and 255 (the HASH algorithm)
+pub fn hash_algorithm(string: &str) -> u32 {
+    let mut result = 0;
+    for char in string.chars() {
+        result += char as u32;
+        result *= 17;
+        result %= 256;
+    }
+    result
+}
+
+/// Initialization sequence
+///
+/// Part 1 hashes the raw steps of the sequence, while part 2 needs the
+/// operation each one of them describes.
+#[derive(Debug)]
+pub struct Sequence {
+    /// Steps of the sequence as they appear in the input
+    pub steps: Vec<String>,
+    /// Label and operation of each step: `Some(focal_length)` for `=` and
+    /// `None` for `-`
+    pub operations: Vec<(String, Option<u32>)>,
+}
+
+/// Solution to the puzzle of day 15
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Sequence;
+    type Answer = u32;
+
+    fn parse(input: &RawInput) -> Result<Self::Input, ParseError> {
+        let line = get_sequence_line(input)?;
+        let mut steps = vec![];
+        let mut operations = vec![];
+        for code in line.text.split(",") {
+            steps.push(String::from(code));
+            operations.push(parse_code(&line, code)?);
+        }
+        Ok(Sequence { steps, operations })
+    }
+
+    fn part1(sequence: &Self::Input) -> Option<u32> {
+        Some(solve_part1(sequence))
+    }
+
+    fn part2(sequence: &Self::Input) -> Option<u32> {
+        Some(solve_part2(sequence))
+    }
+}
+
+fn solve_part1(sequence: &Sequence) -> u32 {
+    let mut result = 0;
+    for code in sequence.steps.iter() {
+        result += hash_algorithm(code);
+    }
+    result
+}
+
+/// Return the line containing the initialization sequence
+fn get_sequence_line(input: &RawInput) -> Result<Line<'_>, ParseError> {
+    match input.lines().next() {
+        Some(line) => Ok(line),
+        None => Err(input.error_at_end("expected initialization sequence")),
+    }
+}
+
+/// Parse a step like `rn=1` or `cm-` into its label and operation
+pub fn parse_code(line: &Line, code: &str) -> Result<(String, Option<u32>), ParseError> {
+    if let Some((label, focal_length)) = code.split_once('=') {
+        match focal_length.parse() {
+            Ok(focal_length) => Ok((String::from(label), Some(focal_length))),
+            Err(_) => Err(line.error(focal_length, "invalid focal length")),
+        }
+    } else if let Some((label, _)) = code.split_once('-') {
+        Ok((String::from(label), None))
+    } else {
+        Err(line.error(code, "invalid code, expected '=' or '-' operation"))
+    }
+}
+
+fn solve_part2(sequence: &Sequence) -> u32 {
+    let mut boxes: HashMap<u32, LensBox> = HashMap::new();
+    for (label, focal_length) in sequence.operations.iter() {
+        let label = label.clone();
+        match focal_length {
+            Some(f) => {
+                let box_index = hash_algorithm(&label);
+                let box_i = boxes.entry(box_index).or_default();
+                box_i.insert(label, *f);
+            }
+            None => {
+                let box_index = hash_algorithm(&label);
+                if let Some(b) = boxes.get_mut(&box_index) {
+                    b.remove(label);
+                    if b.is_empty() {
+                        boxes.remove(&box_index);
+                    }
+                };
+            }
+        }
+    }
+    let mut focusing_power = 0;
+    for box_i in boxes.values() {
+        if let Some(x) = box_i.focusing_power() {
+            focusing_power += x
+        }
+    }
+    focusing_power
+}